@@ -2,15 +2,45 @@ use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use std::collections::{HashMap, HashSet};
 
+/// Selects how [`cluster_list`]/[`cluster_objects`] decide whether a value
+/// joins the cluster currently being built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClusterMode {
+    /// Single-linkage: a value joins the current cluster if it is within
+    /// `tolerance` of the *previous* value. Chains transitively, so a long
+    /// gradient of closely-spaced values can collapse into one giant cluster.
+    Linkage,
+    /// A value joins the current cluster only if it is within `tolerance` of
+    /// the cluster's running *centroid* (mean of its members so far), which
+    /// bounds how wide any single cluster can grow.
+    Centroid,
+}
+
 /// Clusters a list of numbers based on tolerance
 ///
 /// # Arguments
 /// * `xs` - A vector of numbers to cluster
 /// * `tolerance` - The maximum difference between consecutive elements in a cluster
+/// * `mode` - Whether membership is decided against the previous element
+///   ([`ClusterMode::Linkage`]) or the cluster's running centroid
+///   ([`ClusterMode::Centroid`])
 ///
 /// # Returns
 /// A vector of vectors, where each inner vector represents a cluster
 fn cluster_list(
+    xs: Vec<OrderedFloat<f32>>,
+    tolerance: OrderedFloat<f32>,
+    mode: ClusterMode,
+) -> Vec<Vec<OrderedFloat<f32>>> {
+    match mode {
+        ClusterMode::Linkage => cluster_list_linkage(xs, tolerance),
+        ClusterMode::Centroid => cluster_list_centroid(xs, tolerance),
+    }
+}
+
+/// Single-linkage clustering: a value joins the current cluster if it is
+/// within `tolerance` of the previous (sorted) value.
+fn cluster_list_linkage(
     mut xs: Vec<OrderedFloat<f32>>,
     tolerance: OrderedFloat<f32>,
 ) -> Vec<Vec<OrderedFloat<f32>>> {
@@ -40,12 +70,53 @@ fn cluster_list(
     groups
 }
 
+/// Centroid clustering: a value joins the current cluster only if it is
+/// within `tolerance` of the cluster's running centroid (mean of its members
+/// so far), rather than just its previous element. This bounds each
+/// cluster's width, avoiding the single-linkage chaining `cluster_list`
+/// exhibits on a dense gradient of values.
+fn cluster_list_centroid(
+    mut xs: Vec<OrderedFloat<f32>>,
+    tolerance: OrderedFloat<f32>,
+) -> Vec<Vec<OrderedFloat<f32>>> {
+    let zero = OrderedFloat(0.0f32);
+
+    if tolerance == zero || xs.len() < 2 {
+        xs.sort();
+        return xs.into_iter().map(|x| vec![x]).collect();
+    }
+
+    xs.sort();
+    let mut groups: Vec<Vec<OrderedFloat<f32>>> = Vec::new();
+    let mut current_group = vec![xs[0]];
+    let mut sum = xs[0].into_inner();
+    let mut count = 1u32;
+
+    for &x in xs.iter().skip(1) {
+        let centroid = OrderedFloat(sum / count as f32);
+        if x - centroid <= tolerance {
+            current_group.push(x);
+            sum += x.into_inner();
+            count += 1;
+        } else {
+            groups.push(current_group);
+            current_group = vec![x];
+            sum = x.into_inner();
+            count = 1;
+        }
+    }
+
+    groups.push(current_group);
+    groups
+}
+
 /// Creates a dictionary mapping values to their cluster indices.
 ///
 /// # Arguments
 ///
 /// * `values` - A vector of values to cluster.
 /// * `tolerance` - The tolerance value for clustering.
+/// * `mode` - Whether to cluster by single-linkage or running centroid.
 ///
 /// # Returns
 ///
@@ -53,6 +124,7 @@ fn cluster_list(
 fn make_cluster_dict(
     values: Vec<OrderedFloat<f32>>,
     tolerance: OrderedFloat<f32>,
+    mode: ClusterMode,
 ) -> HashMap<OrderedFloat<f32>, usize> {
     let unique_values: Vec<OrderedFloat<f32>> = values
         .into_iter()
@@ -60,7 +132,7 @@ fn make_cluster_dict(
         .into_iter()
         .collect();
 
-    let clusters = cluster_list(unique_values, tolerance);
+    let clusters = cluster_list(unique_values, tolerance, mode);
 
     let mut result = HashMap::new();
     for (cluster_index, cluster) in clusters.into_iter().enumerate() {
@@ -80,6 +152,8 @@ fn make_cluster_dict(
 /// * `xs` - The objects to cluster.
 /// * `key_fn` - A function that extracts a numeric key from each object.
 /// * `tolerance` - The maximum difference for objects to be in the same cluster.
+/// * `mode` - Whether membership is decided by single-linkage or by distance
+///   from the cluster's running centroid.
 ///
 /// # Returns
 ///
@@ -93,6 +167,7 @@ pub(crate) fn cluster_objects<T, F>(
     xs: &[T],
     key_fn: F,
     tolerance: OrderedFloat<f32>,
+    mode: ClusterMode,
 ) -> Vec<Vec<T>>
 where
     T: Clone,
@@ -102,18 +177,22 @@ where
         return vec![];
     }
 
-    let values: Vec<OrderedFloat<f32>> = xs.iter().map(&key_fn).collect();
-    let cluster_dict = make_cluster_dict(values, tolerance);
+    // Evaluate `key_fn` exactly once per element and cache the result,
+    // rather than once to build `values` and again per lookup below — the
+    // key function may do non-trivial string/geometry math.
+    let keyed: Vec<(T, OrderedFloat<f32>)> =
+        xs.iter().map(|x| (x.clone(), key_fn(x))).collect();
+    let values: Vec<OrderedFloat<f32>> = keyed.iter().map(|(_, key)| *key).collect();
+    let cluster_dict = make_cluster_dict(values, tolerance, mode);
 
-    let mut cluster_tuples: Vec<(T, usize)> = xs
-        .iter()
-        .map(|x| {
-            let key_value = OrderedFloat(key_fn(x));
-            let cluster_id = cluster_dict.get(&key_value).copied().unwrap_or(0);
-            (x.clone(), cluster_id)
+    let mut cluster_tuples: Vec<(T, usize)> = keyed
+        .into_iter()
+        .map(|(item, key)| {
+            let cluster_id = cluster_dict.get(&key).copied().unwrap_or(0);
+            (item, cluster_id)
         })
         .collect();
-    cluster_tuples.sort_by_key(|(_, cluster_id)| *cluster_id);
+    cluster_tuples.sort_by_cached_key(|(_, cluster_id)| *cluster_id);
 
     cluster_tuples
         .into_iter()
@@ -123,11 +202,631 @@ where
         .collect()
 }
 
+/// Output of [`cluster_objects_with_outliers`]: the retained clusters plus a
+/// side bucket of objects that didn't belong to any of them.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ClusterOutliers<T> {
+    pub clusters: Vec<Vec<T>>,
+    pub outliers: Vec<T>,
+}
+
+/// Clusters objects like [`cluster_objects`], then pulls out outliers.
+///
+/// A resulting cluster with fewer than `min_cluster_size` members is
+/// unlikely to represent a real row/column (more likely a stray mark, page
+/// number, or rotated caption). Rather than discarding it outright, each of
+/// its members is checked against the nearest "large" cluster's (one with
+/// at least `min_cluster_size` members) centroid: a member within
+/// `outlier_tolerance_multiplier * tolerance` of that centroid is folded
+/// into the large cluster instead (it's most likely part of that real
+/// row/column, just split off at the boundary), and only members farther
+/// than that — or members of a small cluster when no large cluster exists
+/// at all — land in the returned `outliers` bucket.
+///
+/// # Arguments
+///
+/// * `xs` - The objects to cluster.
+/// * `key_fn` - A function that extracts a numeric key from each object.
+/// * `tolerance` - The maximum difference for objects to be in the same cluster.
+/// * `mode` - Whether membership is decided by single-linkage or centroid.
+/// * `min_cluster_size` - Clusters smaller than this are treated as outliers.
+/// * `outlier_tolerance_multiplier` - How many multiples of `tolerance` a
+///   small cluster's member may be from the nearest large cluster's
+///   centroid and still be folded into it rather than reported as an
+///   outlier.
+pub(crate) fn cluster_objects_with_outliers<T, F>(
+    xs: &[T],
+    key_fn: F,
+    tolerance: OrderedFloat<f32>,
+    mode: ClusterMode,
+    min_cluster_size: usize,
+    outlier_tolerance_multiplier: f32,
+) -> ClusterOutliers<T>
+where
+    T: Clone,
+    F: Fn(&T) -> OrderedFloat<f32>,
+{
+    let (mut clusters, small): (Vec<Vec<T>>, Vec<Vec<T>>) = cluster_objects(xs, &key_fn, tolerance, mode)
+        .into_iter()
+        .partition(|cluster| cluster.len() >= min_cluster_size);
+
+    let large_centroids: Vec<f32> = clusters
+        .iter()
+        .map(|cluster| {
+            let sum: f32 = cluster.iter().map(|x| key_fn(x).into_inner()).sum();
+            sum / cluster.len() as f32
+        })
+        .collect();
+
+    let max_outlier_distance = tolerance.into_inner() * outlier_tolerance_multiplier;
+    let mut outliers = Vec::new();
+
+    for small_cluster in small {
+        for item in small_cluster {
+            let key = key_fn(&item).into_inner();
+            let nearest = large_centroids
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| (key - **a).abs().partial_cmp(&(key - **b).abs()).unwrap());
+
+            match nearest {
+                Some((idx, centroid)) if (key - centroid).abs() <= max_outlier_distance => {
+                    clusters[idx].push(item);
+                }
+                _ => outliers.push(item),
+            }
+        }
+    }
+
+    ClusterOutliers { clusters, outliers }
+}
+
+/// Maximum number of Lloyd iterations [`kmeans_cluster_objects`] will run
+/// before returning whatever partition it has reached.
+const KMEANS_MAX_ITERATIONS: usize = 100;
+
+/// Groups objects into exactly `k` clusters on their numeric key via
+/// iterative k-means (Lloyd's algorithm), for callers who know the expected
+/// number of columns/rows up front rather than a tolerance.
+///
+/// # Arguments
+///
+/// * `xs` - The objects to cluster.
+/// * `key_fn` - A function that extracts a numeric key from each object.
+/// * `k` - The number of clusters to produce.
+///
+/// # Returns
+///
+/// Up to `k` clusters in ascending centroid order. Returns one
+/// single-element cluster per object when `k >= xs.len()`.
+pub(crate) fn kmeans_cluster_objects<T, F>(xs: &[T], key_fn: F, k: usize) -> Vec<Vec<T>>
+where
+    T: Clone,
+    F: Fn(&T) -> OrderedFloat<f32>,
+{
+    if xs.is_empty() || k == 0 {
+        return vec![];
+    }
+
+    let keys: Vec<f32> = xs.iter().map(|x| key_fn(x).into_inner()).collect();
+
+    if k >= xs.len() {
+        let mut indices: Vec<usize> = (0..xs.len()).collect();
+        indices.sort_by(|&a, &b| keys[a].partial_cmp(&keys[b]).unwrap());
+        return indices.into_iter().map(|i| vec![xs[i].clone()]).collect();
+    }
+
+    let min_key = keys.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max_key = keys.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+
+    let mut centroids: Vec<f32> = if k == 1 {
+        vec![(min_key + max_key) / 2.0]
+    } else {
+        (0..k)
+            .map(|i| min_key + (max_key - min_key) * i as f32 / (k - 1) as f32)
+            .collect()
+    };
+
+    let mut assignments: Vec<usize> = vec![usize::MAX; keys.len()];
+
+    for _ in 0..KMEANS_MAX_ITERATIONS {
+        let mut changed = false;
+        for (i, &key) in keys.iter().enumerate() {
+            let nearest = nearest_centroid(key, &centroids);
+            if assignments[i] != nearest {
+                assignments[i] = nearest;
+                changed = true;
+            }
+        }
+
+        for c in 0..k {
+            let members: Vec<f32> = (0..keys.len())
+                .filter(|&i| assignments[i] == c)
+                .map(|i| keys[i])
+                .collect();
+
+            if members.is_empty() {
+                // Re-seed this centroid from the point farthest from its
+                // current centroid in the most populous cluster, so an
+                // empty cluster doesn't just stay empty forever.
+                if let Some(donor) = widest_cluster(&assignments, k) {
+                    let farthest = (0..keys.len())
+                        .filter(|&i| assignments[i] == donor)
+                        .max_by(|&a, &b| {
+                            (keys[a] - centroids[donor])
+                                .abs()
+                                .partial_cmp(&(keys[b] - centroids[donor]).abs())
+                                .unwrap()
+                        })
+                        .unwrap();
+                    assignments[farthest] = c;
+                    centroids[c] = keys[farthest];
+                    changed = true;
+                }
+            } else {
+                centroids[c] = members.iter().sum::<f32>() / members.len() as f32;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let mut cluster_tuples: Vec<(T, usize)> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (x.clone(), assignments[i]))
+        .collect();
+    cluster_tuples.sort_by_key(|(_, cluster_id)| *cluster_id);
+
+    let mut clusters: Vec<(f32, Vec<T>)> = cluster_tuples
+        .into_iter()
+        .chunk_by(|(_, cluster_id)| *cluster_id)
+        .into_iter()
+        .map(|(cluster_id, group)| (centroids[cluster_id], group.map(|(item, _)| item).collect()))
+        .collect();
+    clusters.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+
+    clusters.into_iter().map(|(_, cluster)| cluster).collect()
+}
+
+/// Returns the index of the centroid in `centroids` closest to `key` by
+/// absolute difference.
+fn nearest_centroid(key: f32, centroids: &[f32]) -> usize {
+    centroids
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (key - **a).abs().partial_cmp(&(key - **b).abs()).unwrap())
+        .map(|(idx, _)| idx)
+        .unwrap()
+}
+
+/// Returns the index (`0..k`) of the non-empty cluster in `assignments`
+/// with the most members, for re-seeding an empty cluster during k-means
+/// iteration. Returns `None` if every cluster is empty (only possible when
+/// `assignments` is empty).
+fn widest_cluster(assignments: &[usize], k: usize) -> Option<usize> {
+    (0..k)
+        .map(|c| (c, assignments.iter().filter(|&&a| a == c).count()))
+        .filter(|&(_, count)| count > 0)
+        .max_by_key(|&(_, count)| count)
+        .map(|(c, _)| c)
+}
+
+/// A clustering key that knows how to measure distance to another key of
+/// the same type and how to average a slice of itself, generalizing
+/// clustering beyond a single scalar axis (e.g. jointly over (x, y)).
+pub(crate) trait Clusterable: Clone {
+    /// Distance between `self` and `other`. Must be non-negative.
+    fn distance(&self, other: &Self) -> f32;
+
+    /// The centroid (mean) of `items`, or `None` if `items` is empty.
+    fn centroid(items: &[Self]) -> Option<Self>;
+}
+
+impl Clusterable for OrderedFloat<f32> {
+    fn distance(&self, other: &Self) -> f32 {
+        (self.into_inner() - other.into_inner()).abs()
+    }
+
+    fn centroid(items: &[Self]) -> Option<Self> {
+        if items.is_empty() {
+            return None;
+        }
+        let sum: f32 = items.iter().map(|v| v.into_inner()).sum();
+        Some(OrderedFloat(sum / items.len() as f32))
+    }
+}
+
+/// A 2-D point key, for clustering jointly by (x, y) position — e.g.
+/// grouping table cells simultaneously by column and row instead of running
+/// two separate single-axis passes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Point2D {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Clusterable for Point2D {
+    fn distance(&self, other: &Self) -> f32 {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+    }
+
+    fn centroid(items: &[Self]) -> Option<Self> {
+        if items.is_empty() {
+            return None;
+        }
+        let n = items.len() as f32;
+        let sum_x: f32 = items.iter().map(|p| p.x).sum();
+        let sum_y: f32 = items.iter().map(|p| p.y).sum();
+        Some(Point2D {
+            x: sum_x / n,
+            y: sum_y / n,
+        })
+    }
+}
+
+/// Clusters objects by an N-dimensional key via the [`Clusterable`] trait,
+/// generalizing [`cluster_objects`] beyond a single scalar axis.
+///
+/// Like [`ClusterMode::Centroid`], a value joins an existing cluster if it
+/// is within `tolerance` of that cluster's running centroid; otherwise it
+/// starts a new cluster. Clusters are tried in the order they were created.
+/// Unlike the scalar path, `xs` is processed in its given order rather than
+/// sorted first, since `K` has no assumed total order.
+///
+/// # Arguments
+///
+/// * `xs` - The objects to cluster.
+/// * `key_fn` - A function that extracts a `Clusterable` key from each object.
+/// * `tolerance` - The maximum distance from a cluster's centroid for an
+///   object to join it.
+pub(crate) fn cluster_objects_by<T, K, F>(xs: &[T], key_fn: F, tolerance: f32) -> Vec<Vec<T>>
+where
+    T: Clone,
+    K: Clusterable,
+    F: Fn(&T) -> K,
+{
+    if xs.is_empty() {
+        return vec![];
+    }
+
+    let mut keys: Vec<Vec<K>> = Vec::new();
+    let mut items: Vec<Vec<T>> = Vec::new();
+
+    for x in xs {
+        let key = key_fn(x);
+        let target = keys.iter().position(|cluster| {
+            K::centroid(cluster)
+                .map(|centroid| centroid.distance(&key) <= tolerance)
+                .unwrap_or(false)
+        });
+
+        match target {
+            Some(idx) => {
+                keys[idx].push(key);
+                items[idx].push(x.clone());
+            }
+            None => {
+                keys.push(vec![key]);
+                items.push(vec![x.clone()]);
+            }
+        }
+    }
+
+    items
+}
+
+/// Minimal union-find (disjoint-set) structure, used internally by
+/// [`consensus_cluster_objects`] to merge items that are co-associated in a
+/// majority of clustering runs into connected components.
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a != root_b {
+            self.parent[root_a] = root_b;
+        }
+    }
+}
+
+/// Clusters objects by running [`cluster_objects`] once per tolerance in
+/// `tolerances` and taking a consensus, instead of committing to one
+/// hand-tuned tolerance.
+///
+/// For each run, every pair of items placed in the same cluster has its
+/// co-association count incremented. Afterwards, pairs co-associated in a
+/// majority of runs (more than half of `tolerances.len()`) are treated as
+/// connected, and the final clusters are the connected components under
+/// union-find over that relation. This yields stable row/column structure
+/// across a range of plausible tolerances rather than one brittle value.
+///
+/// # Arguments
+///
+/// * `xs` - The objects to cluster.
+/// * `key_fn` - A function that extracts a numeric key from each object.
+/// * `tolerances` - The tolerances to run [`cluster_objects`] with
+///   (in [`ClusterMode::Linkage`] mode).
+///
+/// # Returns
+///
+/// Clusters in ascending key order, for determinism.
+pub(crate) fn consensus_cluster_objects<T, F>(
+    xs: &[T],
+    key_fn: F,
+    tolerances: &[OrderedFloat<f32>],
+) -> Vec<Vec<T>>
+where
+    T: Clone,
+    F: Fn(&T) -> OrderedFloat<f32>,
+{
+    if xs.is_empty() {
+        return vec![];
+    }
+
+    let mut sorted_indices: Vec<usize> = (0..xs.len()).collect();
+    sorted_indices.sort_by(|&a, &b| key_fn(&xs[a]).partial_cmp(&key_fn(&xs[b])).unwrap());
+
+    if tolerances.is_empty() {
+        return sorted_indices.into_iter().map(|i| vec![xs[i].clone()]).collect();
+    }
+
+    let indexed: Vec<(usize, T)> = xs.iter().cloned().enumerate().collect();
+    let mut co_association: HashMap<(usize, usize), u32> = HashMap::new();
+
+    for &tolerance in tolerances {
+        let runs = cluster_objects(
+            &indexed,
+            |(_, item)| key_fn(item),
+            tolerance,
+            ClusterMode::Linkage,
+        );
+
+        for cluster in runs {
+            let indices: Vec<usize> = cluster.iter().map(|(i, _)| *i).collect();
+            for i in 0..indices.len() {
+                for j in (i + 1)..indices.len() {
+                    let pair = (indices[i].min(indices[j]), indices[i].max(indices[j]));
+                    *co_association.entry(pair).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+
+    let majority_threshold = tolerances.len() as f32 / 2.0;
+    let mut union_find = UnionFind::new(xs.len());
+    for (&(a, b), &count) in &co_association {
+        if count as f32 > majority_threshold {
+            union_find.union(a, b);
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..xs.len() {
+        let root = union_find.find(i);
+        groups.entry(root).or_default().push(i);
+    }
+
+    let mut clusters: Vec<Vec<T>> = groups
+        .into_values()
+        .map(|mut indices| {
+            indices.sort_by(|&a, &b| key_fn(&xs[a]).partial_cmp(&key_fn(&xs[b])).unwrap());
+            indices.into_iter().map(|i| xs[i].clone()).collect()
+        })
+        .collect();
+    clusters.sort_by(|a, b| key_fn(&a[0]).partial_cmp(&key_fn(&b[0])).unwrap());
+
+    clusters
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ordered_float::OrderedFloat;
 
+    #[test]
+    fn test_kmeans_cluster_objects_groups_by_proximity() {
+        let xs: Vec<OrderedFloat<f32>> = vec![0.0, 0.1, 0.2, 10.0, 10.1, 20.0]
+            .into_iter()
+            .map(OrderedFloat)
+            .collect();
+
+        let result = kmeans_cluster_objects(&xs, |&x| x, 3);
+        assert_eq!(
+            result,
+            vec![
+                vec![OrderedFloat(0.0), OrderedFloat(0.1), OrderedFloat(0.2)],
+                vec![OrderedFloat(10.0), OrderedFloat(10.1)],
+                vec![OrderedFloat(20.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kmeans_cluster_objects_k_exceeds_len() {
+        let xs: Vec<OrderedFloat<f32>> = vec![3.0, 1.0, 2.0]
+            .into_iter()
+            .map(OrderedFloat)
+            .collect();
+
+        let result = kmeans_cluster_objects(&xs, |&x| x, 10);
+        assert_eq!(
+            result,
+            vec![
+                vec![OrderedFloat(1.0)],
+                vec![OrderedFloat(2.0)],
+                vec![OrderedFloat(3.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kmeans_cluster_objects_empty_input() {
+        let xs: Vec<OrderedFloat<f32>> = Vec::new();
+        assert!(kmeans_cluster_objects(&xs, |&x| x, 3).is_empty());
+    }
+
+    #[test]
+    fn test_cluster_objects_by_scalar_key() {
+        let xs: Vec<OrderedFloat<f32>> = vec![1.0, 1.1, 5.0]
+            .into_iter()
+            .map(OrderedFloat)
+            .collect();
+
+        let result = cluster_objects_by(&xs, |&x| x, 0.5);
+        assert_eq!(
+            result,
+            vec![vec![OrderedFloat(1.0), OrderedFloat(1.1)], vec![OrderedFloat(5.0)]]
+        );
+    }
+
+    #[test]
+    fn test_cluster_objects_by_point2d_key() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Cell {
+            x: f32,
+            y: f32,
+        }
+
+        let cells = vec![
+            Cell { x: 0.0, y: 0.0 },
+            Cell { x: 0.1, y: 0.1 },
+            Cell { x: 10.0, y: 10.0 },
+        ];
+
+        let result = cluster_objects_by(
+            &cells,
+            |c: &Cell| Point2D { x: c.x, y: c.y },
+            1.0,
+        );
+
+        assert_eq!(
+            result,
+            vec![vec![cells[0].clone(), cells[1].clone()], vec![cells[2].clone()]]
+        );
+    }
+
+    #[test]
+    fn test_point2d_centroid() {
+        let points = vec![Point2D { x: 0.0, y: 0.0 }, Point2D { x: 2.0, y: 4.0 }];
+        let centroid = Point2D::centroid(&points).unwrap();
+        assert_eq!(centroid, Point2D { x: 1.0, y: 2.0 });
+        assert!(Point2D::centroid(&[]).is_none());
+    }
+
+    #[test]
+    fn test_cluster_objects_with_outliers_moves_small_clusters_out() {
+        let xs: Vec<OrderedFloat<f32>> = vec![1.0, 1.1, 1.2, 5.0, 9.0, 9.1, 9.2]
+            .into_iter()
+            .map(OrderedFloat)
+            .collect();
+
+        let result = cluster_objects_with_outliers(
+            &xs,
+            |&x| x,
+            OrderedFloat(0.5),
+            ClusterMode::Linkage,
+            2,
+            2.0,
+        );
+
+        assert_eq!(
+            result.clusters,
+            vec![
+                vec![OrderedFloat(1.0), OrderedFloat(1.1), OrderedFloat(1.2)],
+                vec![OrderedFloat(9.0), OrderedFloat(9.1), OrderedFloat(9.2)],
+            ]
+        );
+        assert_eq!(result.outliers, vec![OrderedFloat(5.0)]);
+    }
+
+    #[test]
+    fn test_cluster_objects_with_outliers_folds_nearby_small_cluster_into_large_one() {
+        // 1.3 splits off from the {1.0, 1.05, 1.1} run at tolerance 0.1 (it's
+        // not within 0.1 of 1.1), but its distance to that cluster's
+        // centroid (1.05) is only 0.25, within 3x tolerance — it should be
+        // folded back in rather than reported as an outlier.
+        let xs: Vec<OrderedFloat<f32>> = vec![1.0, 1.05, 1.1, 1.3, 9.0, 9.1, 9.2]
+            .into_iter()
+            .map(OrderedFloat)
+            .collect();
+
+        let result = cluster_objects_with_outliers(
+            &xs,
+            |&x| x,
+            OrderedFloat(0.1),
+            ClusterMode::Linkage,
+            2,
+            3.0,
+        );
+
+        assert_eq!(
+            result.clusters,
+            vec![
+                vec![
+                    OrderedFloat(1.0),
+                    OrderedFloat(1.05),
+                    OrderedFloat(1.1),
+                    OrderedFloat(1.3),
+                ],
+                vec![OrderedFloat(9.0), OrderedFloat(9.1), OrderedFloat(9.2)],
+            ]
+        );
+        assert!(result.outliers.is_empty());
+    }
+
+    #[test]
+    fn test_consensus_cluster_objects_majority_vote() {
+        // At tolerance 0.1 every value is its own cluster; at 0.3 and 0.6
+        // single-linkage chains 1.0 -> 1.2 -> 1.4 into one cluster. With
+        // three tolerances voting, {1.0, 1.2, 1.4} should end up together
+        // (2/3 runs agree on every pair within the group) while 5.0 stays
+        // isolated.
+        let xs: Vec<OrderedFloat<f32>> = vec![1.0, 1.2, 1.4, 5.0]
+            .into_iter()
+            .map(OrderedFloat)
+            .collect();
+        let tolerances = vec![OrderedFloat(0.1), OrderedFloat(0.3), OrderedFloat(0.6)];
+
+        let result = consensus_cluster_objects(&xs, |&x| x, &tolerances);
+
+        assert_eq!(
+            result,
+            vec![
+                vec![OrderedFloat(1.0), OrderedFloat(1.2), OrderedFloat(1.4)],
+                vec![OrderedFloat(5.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_consensus_cluster_objects_empty_tolerances() {
+        let xs: Vec<OrderedFloat<f32>> = vec![2.0, 1.0]
+            .into_iter()
+            .map(OrderedFloat)
+            .collect();
+        let result = consensus_cluster_objects(&xs, |&x| x, &[]);
+        assert_eq!(result, vec![vec![OrderedFloat(1.0)], vec![OrderedFloat(2.0)]]);
+    }
+
     #[test]
     fn test_cluster_list() {
         let a: Vec<OrderedFloat<f32>> = vec![1.0, 2.0, 3.0, 4.0]
@@ -135,13 +834,19 @@ mod tests {
             .map(OrderedFloat)
             .collect();
         let expected: Vec<Vec<OrderedFloat<f32>>> = a.iter().map(|&x| vec![x]).collect();
-        assert_eq!(cluster_list(a.clone(), OrderedFloat(0.0)), expected);
+        assert_eq!(
+            cluster_list(a.clone(), OrderedFloat(0.0), ClusterMode::Linkage),
+            expected
+        );
 
         let a: Vec<OrderedFloat<f32>> = vec![1.0, 2.0, 3.0, 4.0]
             .into_iter()
             .map(OrderedFloat)
             .collect();
-        assert_eq!(cluster_list(a.clone(), OrderedFloat(1.0)), vec![a]);
+        assert_eq!(
+            cluster_list(a.clone(), OrderedFloat(1.0), ClusterMode::Linkage),
+            vec![a]
+        );
 
         let a: Vec<OrderedFloat<f32>> = vec![1.0, 2.0, 5.0, 6.0]
             .into_iter()
@@ -151,7 +856,37 @@ mod tests {
             vec![OrderedFloat(1.0), OrderedFloat(2.0)],
             vec![OrderedFloat(5.0), OrderedFloat(6.0)],
         ];
-        assert_eq!(cluster_list(a, OrderedFloat(1.0)), expected);
+        assert_eq!(
+            cluster_list(a, OrderedFloat(1.0), ClusterMode::Linkage),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_cluster_list_centroid_bounds_cluster_width() {
+        // Single-linkage chains this gradient (each step is within 1.0 of its
+        // predecessor) into one giant cluster; centroid mode should split it
+        // once the running mean drifts more than `tolerance` from the next
+        // value.
+        let a: Vec<OrderedFloat<f32>> = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]
+            .into_iter()
+            .map(OrderedFloat)
+            .collect();
+
+        assert_eq!(
+            cluster_list(a.clone(), OrderedFloat(1.0), ClusterMode::Linkage),
+            vec![a.clone()]
+        );
+
+        let centroid_result = cluster_list(a, OrderedFloat(1.0), ClusterMode::Centroid);
+        assert_eq!(
+            centroid_result,
+            vec![
+                vec![OrderedFloat(0.0), OrderedFloat(1.0)],
+                vec![OrderedFloat(2.0), OrderedFloat(3.0)],
+                vec![OrderedFloat(4.0), OrderedFloat(5.0)],
+            ]
+        );
     }
 
     #[test]
@@ -165,6 +900,7 @@ mod tests {
             &a,
             |s: &String| OrderedFloat(s.len() as f32),
             OrderedFloat(0.0),
+            ClusterMode::Linkage,
         );
 
         assert_eq!(
@@ -201,7 +937,12 @@ mod tests {
             },
         ];
 
-        let result = cluster_objects(&b, |item: &Item| OrderedFloat(item.x), OrderedFloat(0.0));
+        let result = cluster_objects(
+            &b,
+            |item: &Item| OrderedFloat(item.x),
+            OrderedFloat(0.0),
+            ClusterMode::Linkage,
+        );
         assert_eq!(
             result,
             vec![
@@ -218,6 +959,7 @@ mod tests {
                 _ => OrderedFloat(0.0),
             },
             OrderedFloat(0.0),
+            ClusterMode::Linkage,
         );
         assert_eq!(
             result,
@@ -0,0 +1,369 @@
+use crate::objects::*;
+use crate::words::Word;
+use ordered_float::OrderedFloat;
+
+/// Default side length, in PDF points, of a density grid bucket.
+static DEFAULT_BUCKET_SIZE: f32 = 5.0;
+
+/// Which per-object density table a [`CharDensityIndex`] query targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DensityKind {
+    /// Query the character density table.
+    Char,
+    /// Query the word density table.
+    Word,
+}
+
+/// A summed-area table over a fixed-size grid, answering "how many items
+/// fall inside this rectangle" in O(1) after an O(rows * cols) build.
+///
+/// Each item contributes to the grid cell containing its bounding-box
+/// center. `sums[i][j]` holds the total count over the half-open grid
+/// range `[0, i) x [0, j)`, so a rectangular query is four lookups and no
+/// per-item scan.
+struct SummedAreaTable {
+    origin_x: OrderedFloat<f32>,
+    origin_y: OrderedFloat<f32>,
+    bucket_size: OrderedFloat<f32>,
+    cols: usize,
+    rows: usize,
+    sums: Vec<Vec<u32>>,
+}
+
+impl SummedAreaTable {
+    /// Builds a summed-area table over `bboxes`, discretized into
+    /// `bucket_size`-sided cells starting at `(origin_x, origin_y)`.
+    fn build(
+        bboxes: impl Iterator<Item = BboxKey>,
+        origin_x: OrderedFloat<f32>,
+        origin_y: OrderedFloat<f32>,
+        bucket_size: OrderedFloat<f32>,
+        cols: usize,
+        rows: usize,
+    ) -> Self {
+        let mut grid = vec![vec![0u32; cols]; rows];
+        for (x1, y1, x2, y2) in bboxes {
+            let cx = (x1 + x2) / OrderedFloat(2.0);
+            let cy = (y1 + y2) / OrderedFloat(2.0);
+            let col = Self::bucket_index(cx, origin_x, bucket_size, cols);
+            let row = Self::bucket_index(cy, origin_y, bucket_size, rows);
+            grid[row][col] += 1;
+        }
+
+        let mut sums = vec![vec![0u32; cols + 1]; rows + 1];
+        for i in 0..rows {
+            for j in 0..cols {
+                sums[i + 1][j + 1] = grid[i][j] + sums[i][j + 1] + sums[i + 1][j] - sums[i][j];
+            }
+        }
+
+        Self {
+            origin_x,
+            origin_y,
+            bucket_size,
+            cols,
+            rows,
+            sums,
+        }
+    }
+
+    /// Clamps a coordinate to its bucket index along one axis.
+    fn bucket_index(
+        coord: OrderedFloat<f32>,
+        origin: OrderedFloat<f32>,
+        bucket_size: OrderedFloat<f32>,
+        len: usize,
+    ) -> usize {
+        if len == 0 {
+            return 0;
+        }
+        let offset = ((coord - origin) / bucket_size).into_inner();
+        (offset.floor().max(0.0) as usize).min(len - 1)
+    }
+
+    /// Returns the number of indexed items whose bbox center falls in any
+    /// grid bucket touched by `(x1, y1, x2, y2)`. Counts are at bucket
+    /// granularity: each corner maps to the bucket containing it, so a
+    /// corner lying exactly on a bucket boundary pulls in the bucket past it.
+    fn count_in_region(&self, bbox: BboxKey) -> u32 {
+        if self.rows == 0 || self.cols == 0 {
+            return 0;
+        }
+        let (x1, y1, x2, y2) = bbox;
+        if x2 < x1 || y2 < y1 {
+            return 0;
+        }
+
+        let c1 = Self::bucket_index(x1, self.origin_x, self.bucket_size, self.cols);
+        let c2 = Self::bucket_index(x2, self.origin_x, self.bucket_size, self.cols);
+        let r1 = Self::bucket_index(y1, self.origin_y, self.bucket_size, self.rows);
+        let r2 = Self::bucket_index(y2, self.origin_y, self.bucket_size, self.rows);
+
+        // S has an extra leading zero row/column, so the inclusive
+        // [r1, r2] x [c1, c2] range reads as [r1, r2+1] x [c1, c2+1] here.
+        let total = self.sums[r2 + 1][c2 + 1];
+        let above = self.sums[r1][c2 + 1];
+        let left = self.sums[r2 + 1][c1];
+        let above_left = self.sums[r1][c1];
+        total - above - left + above_left
+    }
+}
+
+/// A pair of [`SummedAreaTable`]s split by text rotation, so that vertical
+/// text is indexed separately from upright text.
+struct RotationIndexedTable {
+    upright: SummedAreaTable,
+    rotated: SummedAreaTable,
+}
+
+impl RotationIndexedTable {
+    fn count_in_region(&self, bbox: BboxKey) -> u32 {
+        self.upright.count_in_region(bbox) + self.rotated.count_in_region(bbox)
+    }
+}
+
+/// Returns `true` if `rotation_degrees` reads top-to-bottom or
+/// bottom-to-top rather than left-to-right or right-to-left.
+fn is_rotated(rotation_degrees: OrderedFloat<f32>) -> bool {
+    let r = rotation_degrees.into_inner().rem_euclid(180.0);
+    (45.0..135.0).contains(&r)
+}
+
+/// A spatial index answering "how many characters/words fall inside this
+/// rectangle" in O(1), for cheaply testing whether a candidate gridline
+/// separates text or cuts through a word during table-cell detection.
+///
+/// Built once per page from the same [`Char`]/[`Word`] bboxes
+/// [`crate::words::WordExtractor`] works from, by discretizing the page
+/// into a grid of `bucket_size`-sided buckets and precomputing a
+/// cumulative sum over it (see [`SummedAreaTable`]).
+pub(crate) struct CharDensityIndex {
+    chars: RotationIndexedTable,
+    words: RotationIndexedTable,
+}
+
+impl CharDensityIndex {
+    /// Builds a density index over `chars` and `words` within `page_bbox`,
+    /// using `bucket_size`-sided grid cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_bbox` - The region the grid covers; bboxes outside it are
+    ///   clamped to the nearest edge bucket rather than dropped.
+    /// * `chars` - The characters to index.
+    /// * `words` - The words to index.
+    /// * `bucket_size` - The side length of a grid cell, in PDF points.
+    pub(crate) fn new(
+        page_bbox: BboxKey,
+        chars: &[Char],
+        words: &[Word],
+        bucket_size: f32,
+    ) -> Self {
+        let (x1, y1, x2, y2) = page_bbox;
+        let bucket_size = OrderedFloat(bucket_size.max(f32::EPSILON));
+        let cols = (((x2 - x1) / bucket_size).into_inner().ceil().max(1.0)) as usize;
+        let rows = (((y2 - y1) / bucket_size).into_inner().ceil().max(1.0)) as usize;
+
+        let (chars_upright, chars_rotated): (Vec<&Char>, Vec<&Char>) =
+            chars.iter().partition(|c| !is_rotated(c.rotation_degrees));
+        let (words_upright, words_rotated): (Vec<&Word>, Vec<&Word>) =
+            words.iter().partition(|w| !is_rotated(w.rotation_degrees));
+        let chars_upright: Vec<BboxKey> = chars_upright.iter().map(|c| c.bbox).collect();
+        let chars_rotated: Vec<BboxKey> = chars_rotated.iter().map(|c| c.bbox).collect();
+        let words_upright: Vec<BboxKey> = words_upright.iter().map(|w| w.bbox).collect();
+        let words_rotated: Vec<BboxKey> = words_rotated.iter().map(|w| w.bbox).collect();
+
+        Self {
+            chars: RotationIndexedTable {
+                upright: SummedAreaTable::build(
+                    chars_upright.into_iter(),
+                    x1,
+                    y1,
+                    bucket_size,
+                    cols,
+                    rows,
+                ),
+                rotated: SummedAreaTable::build(
+                    chars_rotated.into_iter(),
+                    x1,
+                    y1,
+                    bucket_size,
+                    cols,
+                    rows,
+                ),
+            },
+            words: RotationIndexedTable {
+                upright: SummedAreaTable::build(
+                    words_upright.into_iter(),
+                    x1,
+                    y1,
+                    bucket_size,
+                    cols,
+                    rows,
+                ),
+                rotated: SummedAreaTable::build(
+                    words_rotated.into_iter(),
+                    x1,
+                    y1,
+                    bucket_size,
+                    cols,
+                    rows,
+                ),
+            },
+        }
+    }
+
+    /// Returns the number of `kind`s whose bbox center falls in a grid
+    /// bucket touched by `bbox` (see [`SummedAreaTable::count_in_region`]).
+    pub(crate) fn count_in_region(&self, kind: DensityKind, bbox: BboxKey) -> u32 {
+        match kind {
+            DensityKind::Char => self.chars.count_in_region(bbox),
+            DensityKind::Word => self.words.count_in_region(bbox),
+        }
+    }
+
+    /// Returns `true` if no `kind` has a bbox center in a grid bucket
+    /// touched by `bbox`.
+    pub(crate) fn is_region_empty(&self, kind: DensityKind, bbox: BboxKey) -> bool {
+        self.count_in_region(kind, bbox) == 0
+    }
+}
+
+/// Builds a [`CharDensityIndex`] using [`DEFAULT_BUCKET_SIZE`]-sided grid
+/// cells.
+pub(crate) fn default_density_index(
+    page_bbox: BboxKey,
+    chars: &[Char],
+    words: &[Word],
+) -> CharDensityIndex {
+    CharDensityIndex::new(page_bbox, chars, words, DEFAULT_BUCKET_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pdfium_render::prelude::PdfColor;
+
+    fn char_at(x1: f32, y1: f32, x2: f32, y2: f32, rotation_degrees: f32) -> Char {
+        Char {
+            unicode_char: Some("a".to_string()),
+            bbox: (
+                OrderedFloat(x1),
+                OrderedFloat(y1),
+                OrderedFloat(x2),
+                OrderedFloat(y2),
+            ),
+            rotation_degrees: OrderedFloat(rotation_degrees),
+            upright: rotation_degrees == 0.0,
+            font_size: OrderedFloat(10.0),
+            font_name: None,
+            fill_color: PdfColor::new(0, 0, 0, 255),
+            text_matrix: (1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+        }
+    }
+
+    #[test]
+    fn test_summed_area_table_counts_region() {
+        let bboxes = vec![
+            (
+                OrderedFloat(1.0),
+                OrderedFloat(1.0),
+                OrderedFloat(2.0),
+                OrderedFloat(2.0),
+            ),
+            (
+                OrderedFloat(11.0),
+                OrderedFloat(1.0),
+                OrderedFloat(12.0),
+                OrderedFloat(2.0),
+            ),
+            (
+                OrderedFloat(21.0),
+                OrderedFloat(21.0),
+                OrderedFloat(22.0),
+                OrderedFloat(22.0),
+            ),
+        ];
+        let table = SummedAreaTable::build(
+            bboxes.into_iter(),
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(10.0),
+            3,
+            3,
+        );
+
+        assert_eq!(
+            table.count_in_region((
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(30.0),
+                OrderedFloat(30.0)
+            )),
+            3
+        );
+        // The (10, 10) corner lies in bucket (1, 1), so buckets (0..=1, 0..=1)
+        // are counted: the items centred at (1.5, 1.5) and (11.5, 1.5).
+        assert_eq!(
+            table.count_in_region((
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(10.0)
+            )),
+            2
+        );
+        assert_eq!(
+            table.count_in_region((
+                OrderedFloat(15.0),
+                OrderedFloat(15.0),
+                OrderedFloat(19.0),
+                OrderedFloat(19.0)
+            )),
+            0
+        );
+    }
+
+    #[test]
+    fn test_is_rotated() {
+        assert!(!is_rotated(OrderedFloat(0.0)));
+        assert!(!is_rotated(OrderedFloat(180.0)));
+        assert!(is_rotated(OrderedFloat(90.0)));
+        assert!(is_rotated(OrderedFloat(270.0)));
+    }
+
+    #[test]
+    fn test_char_density_index_count_and_empty() {
+        let chars = vec![
+            char_at(1.0, 1.0, 2.0, 2.0, 0.0),
+            char_at(11.0, 11.0, 12.0, 12.0, 0.0),
+            char_at(1.0, 1.0, 2.0, 2.0, 90.0),
+        ];
+        let page_bbox = (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(20.0),
+            OrderedFloat(20.0),
+        );
+        let index = CharDensityIndex::new(page_bbox, &chars, &[], 5.0);
+
+        let region = (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(3.0),
+            OrderedFloat(3.0),
+        );
+        // Both the upright and the rotated char at (1,1)-(2,2) fall inside.
+        assert_eq!(index.count_in_region(DensityKind::Char, region), 2);
+        assert!(!index.is_region_empty(DensityKind::Char, region));
+
+        let far_region = (
+            OrderedFloat(15.0),
+            OrderedFloat(0.0),
+            OrderedFloat(20.0),
+            OrderedFloat(5.0),
+        );
+        assert!(index.is_region_empty(DensityKind::Char, far_region));
+        assert!(index.is_region_empty(DensityKind::Word, far_region));
+    }
+}
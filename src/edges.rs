@@ -1,4 +1,4 @@
-use crate::clusters::cluster_objects;
+use crate::clusters::{cluster_objects, ClusterMode};
 use crate::objects::*;
 use crate::settings::*;
 use crate::words::Word;
@@ -29,12 +29,17 @@ enum EdgeAttr {
 ///
 /// * `words` - A slice of Word objects to analyze.
 /// * `word_threshold` - Minimum number of words required in a cluster to create edges.
+/// * `tolerance` - Maximum gap between word top positions to cluster them together.
 ///
 /// # Returns
 ///
 /// A vector of horizontal Edge objects derived from word positions.
-pub(crate) fn words_to_edges_h(words: &[Word], word_threshold: usize) -> Vec<Edge> {
-    let by_top = cluster_objects(words, |w: &Word| w.bbox.1, OrderedFloat(1.0));
+pub(crate) fn words_to_edges_h(
+    words: &[Word],
+    word_threshold: usize,
+    tolerance: OrderedFloat<f32>,
+) -> Vec<Edge> {
+    let by_top = cluster_objects(words, |w: &Word| w.bbox.1, tolerance, ClusterMode::Linkage);
 
     let large_clusters: Vec<_> = by_top
         .into_iter()
@@ -95,7 +100,7 @@ pub(crate) fn words_to_edges_h(words: &[Word], word_threshold: usize) -> Vec<Edg
 /// # Returns
 ///
 /// `true` if the bounding boxes overlap, `false` otherwise.
-fn get_bbox_overlap(b1: &BboxKey, b2: &BboxKey) -> bool {
+pub(crate) fn get_bbox_overlap(b1: &BboxKey, b2: &BboxKey) -> bool {
     let (b1_x1, b1_y1, b1_x2, b1_y2) = b1;
     let (b2_x1, b2_y1, b2_x2, b2_y2) = b2;
     let (max_x1, max_y1, min_x2, min_y2) = (
@@ -116,14 +121,24 @@ fn get_bbox_overlap(b1: &BboxKey, b2: &BboxKey) -> bool {
 ///
 /// * `words` - A slice of Word objects to analyze.
 /// * `word_threshold` - Minimum number of words required in a cluster to create edges.
+/// * `tolerance` - Maximum gap between word x-positions to cluster them together.
 ///
 /// # Returns
 ///
 /// A vector of vertical Edge objects derived from word positions.
-pub fn words_to_edges_v(words: &[Word], word_threshold: usize) -> Vec<Edge> {
-    let by_x0 = cluster_objects(words, |w| w.bbox.0, OrderedFloat(1.0));
-    let by_x1 = cluster_objects(words, |w| w.bbox.2, OrderedFloat(1.0));
-    let by_center = cluster_objects(words, |w| (w.bbox.0 + w.bbox.2) / 2.0, OrderedFloat(1.0));
+pub fn words_to_edges_v(
+    words: &[Word],
+    word_threshold: usize,
+    tolerance: OrderedFloat<f32>,
+) -> Vec<Edge> {
+    let by_x0 = cluster_objects(words, |w| w.bbox.0, tolerance, ClusterMode::Linkage);
+    let by_x1 = cluster_objects(words, |w| w.bbox.2, tolerance, ClusterMode::Linkage);
+    let by_center = cluster_objects(
+        words,
+        |w| (w.bbox.0 + w.bbox.2) / 2.0,
+        tolerance,
+        ClusterMode::Linkage,
+    );
 
     let mut clusters: Vec<Vec<Word>> = by_x0;
     clusters.extend(by_x1);
@@ -198,6 +213,229 @@ pub fn words_to_edges_v(words: &[Word], word_threshold: usize) -> Vec<Edge> {
     edges
 }
 
+/// Bin width (in points) used by the whitespace-gutter projection strategy
+/// when building coverage histograms.
+const PROJECTION_BIN_SIZE: f32 = 1.0;
+
+/// Builds a 1-D coverage histogram over `[min, max]`, binned at
+/// `PROJECTION_BIN_SIZE`, counting how many of `spans` overlap each bin.
+///
+/// # Arguments
+///
+/// * `spans` - The (start, end) extents to project, e.g. word x1/x2.
+/// * `min` - Lower bound of the axis being projected.
+/// * `max` - Upper bound of the axis being projected.
+///
+/// # Returns
+///
+/// Per-bin coverage counts, indexed from `min` in steps of `PROJECTION_BIN_SIZE`.
+fn coverage_histogram(
+    spans: &[(OrderedFloat<f32>, OrderedFloat<f32>)],
+    min: f32,
+    max: f32,
+) -> Vec<usize> {
+    let bin_count = (((max - min) / PROJECTION_BIN_SIZE).ceil() as usize).max(1);
+    let mut histogram = vec![0usize; bin_count];
+    let last_bin = bin_count - 1;
+    for &(start, end) in spans {
+        let start_bin = (((*start - min) / PROJECTION_BIN_SIZE).floor() as isize)
+            .clamp(0, last_bin as isize) as usize;
+        // The end is exclusive, so the last covered bin is the one just
+        // before it (e.g. a span ending exactly on a bin boundary doesn't
+        // cover that next bin at all).
+        let end_bin = ((((*end - min) / PROJECTION_BIN_SIZE).ceil() as isize) - 1)
+            .clamp(start_bin as isize, last_bin as isize) as usize;
+        for bin in histogram.iter_mut().take(end_bin + 1).skip(start_bin) {
+            *bin += 1;
+        }
+    }
+    histogram
+}
+
+/// Finds maximal runs of consecutive low-coverage bins ("gutters") in
+/// `histogram` that are at least `min_gutter_width` wide.
+///
+/// # Arguments
+///
+/// * `histogram` - Per-bin coverage counts, as returned by `coverage_histogram`.
+/// * `min` - The axis coordinate corresponding to bin 0.
+/// * `coverage_threshold` - Maximum coverage a bin may have and still count
+///   toward a gutter.
+/// * `min_gutter_width` - Minimum width (in axis units) a run must span to
+///   be reported.
+///
+/// # Returns
+///
+/// The (start, end) coordinates of each qualifying gutter, in ascending order.
+fn find_gutters(
+    histogram: &[usize],
+    min: f32,
+    coverage_threshold: usize,
+    min_gutter_width: f32,
+) -> Vec<(f32, f32)> {
+    let mut gutters = Vec::new();
+    let mut run_start: Option<usize> = None;
+    for (i, &coverage) in histogram.iter().enumerate() {
+        if coverage <= coverage_threshold {
+            run_start.get_or_insert(i);
+        } else if let Some(start) = run_start.take() {
+            record_gutter_if_wide_enough(&mut gutters, start, i, min, min_gutter_width);
+        }
+    }
+    if let Some(start) = run_start {
+        record_gutter_if_wide_enough(&mut gutters, start, histogram.len(), min, min_gutter_width);
+    }
+    gutters
+}
+
+/// Pushes the gutter spanning bins `[start_bin, end_bin)` onto `gutters` if
+/// its width meets `min_gutter_width`.
+fn record_gutter_if_wide_enough(
+    gutters: &mut Vec<(f32, f32)>,
+    start_bin: usize,
+    end_bin: usize,
+    min: f32,
+    min_gutter_width: f32,
+) {
+    let start = min + start_bin as f32 * PROJECTION_BIN_SIZE;
+    let end = min + end_bin as f32 * PROJECTION_BIN_SIZE;
+    if end - start >= min_gutter_width {
+        gutters.push((start, end));
+    }
+}
+
+/// Converts words into vertical edges by detecting whitespace gutters.
+///
+/// Unlike [`words_to_edges_v`], which clusters word alignment, this builds a
+/// coverage histogram of word x-extents (inspired by Tesseract's textline
+/// projection) and places an edge through the center of every sufficiently
+/// wide, sufficiently empty gutter. This finds columns separated by ragged
+/// whitespace that alignment clustering would miss.
+///
+/// # Arguments
+///
+/// * `words` - A slice of Word objects to analyze.
+/// * `min_gutter_width` - Minimum gutter width (in points) to emit an edge for.
+/// * `coverage_threshold` - Maximum word coverage a bin may have and still
+///   count as part of a gutter.
+///
+/// # Returns
+///
+/// A vector of vertical Edge objects, one per detected gutter, spanning the
+/// min-top/max-bottom of the words that produced the histogram.
+pub(crate) fn words_to_edges_v_projection(
+    words: &[Word],
+    min_gutter_width: OrderedFloat<f32>,
+    coverage_threshold: usize,
+) -> Vec<Edge> {
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let min_x = words
+        .iter()
+        .map(|w| w.bbox.0)
+        .fold(OrderedFloat(f32::INFINITY), cmp::min);
+    let max_x = words
+        .iter()
+        .map(|w| w.bbox.2)
+        .fold(OrderedFloat(f32::NEG_INFINITY), cmp::max);
+    let min_top = words
+        .iter()
+        .map(|w| w.bbox.1)
+        .fold(OrderedFloat(f32::INFINITY), cmp::min);
+    let max_bottom = words
+        .iter()
+        .map(|w| w.bbox.3)
+        .fold(OrderedFloat(f32::NEG_INFINITY), cmp::max);
+
+    let spans: Vec<(OrderedFloat<f32>, OrderedFloat<f32>)> =
+        words.iter().map(|w| (w.bbox.0, w.bbox.2)).collect();
+    let histogram = coverage_histogram(&spans, *min_x, *max_x);
+    let gutters = find_gutters(&histogram, *min_x, coverage_threshold, *min_gutter_width);
+
+    gutters
+        .into_iter()
+        .map(|(start, end)| {
+            let x = OrderedFloat((start + end) / 2.0);
+            Edge {
+                orientation: Orientation::Vertical,
+                x1: x,
+                y1: min_top,
+                x2: x,
+                y2: max_bottom,
+                width: OrderedFloat(1.0),
+                color: PdfColor::new(0, 0, 0, 255),
+            }
+        })
+        .collect()
+}
+
+/// Converts words into horizontal edges by detecting whitespace gutters.
+///
+/// The transposed counterpart of [`words_to_edges_v_projection`]: builds a
+/// coverage histogram of word y-extents and places an edge through the
+/// center of every sufficiently wide, sufficiently empty gutter.
+///
+/// # Arguments
+///
+/// * `words` - A slice of Word objects to analyze.
+/// * `min_gutter_width` - Minimum gutter width (in points) to emit an edge for.
+/// * `coverage_threshold` - Maximum word coverage a bin may have and still
+///   count as part of a gutter.
+///
+/// # Returns
+///
+/// A vector of horizontal Edge objects, one per detected gutter, spanning
+/// the min-left/max-right of the words that produced the histogram.
+pub(crate) fn words_to_edges_h_projection(
+    words: &[Word],
+    min_gutter_width: OrderedFloat<f32>,
+    coverage_threshold: usize,
+) -> Vec<Edge> {
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let min_y = words
+        .iter()
+        .map(|w| w.bbox.1)
+        .fold(OrderedFloat(f32::INFINITY), cmp::min);
+    let max_y = words
+        .iter()
+        .map(|w| w.bbox.3)
+        .fold(OrderedFloat(f32::NEG_INFINITY), cmp::max);
+    let min_left = words
+        .iter()
+        .map(|w| w.bbox.0)
+        .fold(OrderedFloat(f32::INFINITY), cmp::min);
+    let max_right = words
+        .iter()
+        .map(|w| w.bbox.2)
+        .fold(OrderedFloat(f32::NEG_INFINITY), cmp::max);
+
+    let spans: Vec<(OrderedFloat<f32>, OrderedFloat<f32>)> =
+        words.iter().map(|w| (w.bbox.1, w.bbox.3)).collect();
+    let histogram = coverage_histogram(&spans, *min_y, *max_y);
+    let gutters = find_gutters(&histogram, *min_y, coverage_threshold, *min_gutter_width);
+
+    gutters
+        .into_iter()
+        .map(|(start, end)| {
+            let y = OrderedFloat((start + end) / 2.0);
+            Edge {
+                orientation: Orientation::Horizontal,
+                x1: min_left,
+                y1: y,
+                x2: max_right,
+                y2: y,
+                width: OrderedFloat(1.0),
+                color: PdfColor::new(0, 0, 0, 255),
+            }
+        })
+        .collect()
+}
+
 /// Moves an edge by a specified value in the given orientation.
 ///
 /// # Arguments
@@ -247,7 +485,7 @@ fn snap_objects(edges: Vec<Edge>, attr: EdgeAttr, tolerance: OrderedFloat<f32>)
         EdgeAttr::X1 => |edge: &Edge| edge.x1,
         EdgeAttr::Y1 => |edge: &Edge| edge.y1,
     };
-    let clusters = cluster_objects(&edges, attr_getter, tolerance);
+    let clusters = cluster_objects(&edges, attr_getter, tolerance, ClusterMode::Linkage);
     let mut result = Vec::new();
     for cluster in clusters {
         let avg = cluster
@@ -317,9 +555,198 @@ fn join_edge_group(
     result
 }
 
-/// Merges edges of a single orientation by snapping and joining.
+/// Absolute tolerance (in points) used by [`is_dash_run`] when deciding
+/// whether segment lengths, or the gaps between them, cluster tightly
+/// enough around a single value to be the regular rhythm of a dashed or
+/// dotted rule.
+const DASH_UNIFORMITY_TOLERANCE: f32 = 1.0;
+
+/// Checks whether every value in `values` lies within `tolerance` of the
+/// set's mean, i.e. the values cluster around one consistent number rather
+/// than varying widely.
+fn is_roughly_uniform(values: &[f32], tolerance: f32) -> bool {
+    if values.len() < 2 {
+        return true;
+    }
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    values.iter().all(|v| (v - mean).abs() <= tolerance)
+}
+
+/// Returns `true` if `segments` look like the regularly-spaced pieces of a
+/// single dashed or dotted rule: consecutive segments are collinear with
+/// gaps no wider than `dash_max_gap`, and both the segment lengths and the
+/// gaps between them cluster around a consistent value (per
+/// [`is_roughly_uniform`]) rather than varying the way unrelated short
+/// edges would.
+fn is_dash_run(segments: &[Edge], orient: Orientation, dash_max_gap: OrderedFloat<f32>) -> bool {
+    if segments.len() < 2 {
+        return false;
+    }
+    let (get_min, get_max): (fn(&Edge) -> OrderedFloat<f32>, fn(&Edge) -> OrderedFloat<f32>) =
+        match orient {
+            Orientation::Vertical => (|e| e.y1, |e| e.y2),
+            Orientation::Horizontal => (|e| e.x1, |e| e.x2),
+        };
+
+    let mut gaps = Vec::with_capacity(segments.len() - 1);
+    for pair in segments.windows(2) {
+        let gap = get_min(&pair[1]) - get_max(&pair[0]);
+        if gap < OrderedFloat(0.0) || gap > dash_max_gap {
+            return false;
+        }
+        gaps.push(gap.into_inner());
+    }
+
+    let lengths: Vec<f32> = segments
+        .iter()
+        .map(|e| (get_max(e) - get_min(e)).into_inner())
+        .collect();
+
+    is_roughly_uniform(&lengths, DASH_UNIFORMITY_TOLERANCE)
+        && is_roughly_uniform(&gaps, DASH_UNIFORMITY_TOLERANCE)
+}
+
+/// Merges a run of dash/dot segments (as identified by [`is_dash_run`])
+/// into one edge spanning the first segment's start to the last segment's
+/// end, carrying over the first segment's color and width.
+fn merge_dash_run(segments: &[Edge], orient: Orientation) -> Edge {
+    let first = &segments[0];
+    let last = &segments[segments.len() - 1];
+    match orient {
+        Orientation::Vertical => Edge {
+            orientation: Orientation::Vertical,
+            x1: first.x1,
+            y1: first.y1,
+            x2: first.x1,
+            y2: last.y2,
+            width: first.width,
+            color: first.color,
+        },
+        Orientation::Horizontal => Edge {
+            orientation: Orientation::Horizontal,
+            x1: first.x1,
+            y1: first.y1,
+            x2: last.x2,
+            y2: first.y1,
+            width: first.width,
+            color: first.color,
+        },
+    }
+}
+
+/// Stitches dashed or dotted rules within a single snapped coordinate
+/// group (e.g. all edges sharing the same `x1`) into one logical edge per
+/// run.
+///
+/// `join_edge_group`'s join tolerance is tuned for solid lines and never
+/// coalesces the dozens of tiny collinear segments a dashed border arrives
+/// as, since the gaps between them are deliberate, not noise. This scans
+/// segments in order along the edge's own axis and greedily extends each
+/// run for as long as it keeps satisfying [`is_dash_run`]; a run that
+/// reaches `min_dash_count` segments is replaced by a single stitched
+/// edge, and segments that never join such a run are passed through
+/// unchanged. A `dash_max_gap` of zero disables the pass entirely, so
+/// solid-line behavior is unchanged unless a caller opts in.
+fn stitch_dashes(
+    mut edges: Vec<Edge>,
+    orient: Orientation,
+    dash_max_gap: OrderedFloat<f32>,
+    min_dash_count: usize,
+) -> Vec<Edge> {
+    if dash_max_gap <= OrderedFloat(0.0) || edges.len() < 2 {
+        return edges;
+    }
+    let get_min: fn(&Edge) -> OrderedFloat<f32> = match orient {
+        Orientation::Vertical => |e| e.y1,
+        Orientation::Horizontal => |e| e.x1,
+    };
+    edges.sort_by_key(get_min);
+
+    let mut result = Vec::with_capacity(edges.len());
+    let mut start = 0;
+    while start < edges.len() {
+        let mut end = start + 1;
+        while end < edges.len() && is_dash_run(&edges[start..=end], orient, dash_max_gap) {
+            end += 1;
+        }
+        let run = &edges[start..end];
+        if run.len() >= min_dash_count {
+            result.push(merge_dash_run(run, orient));
+        } else {
+            result.extend_from_slice(run);
+        }
+        start = end;
+    }
+    result
+}
+
+/// Bucket width (in points) used when quantizing an edge's stroke width
+/// for style-aware grouping, so near-identical widths (e.g. anti-aliasing
+/// jitter) still fall in the same bucket.
+const STYLE_WIDTH_BUCKET: f32 = 0.5;
+/// Bucket size (out of 256) used when quantizing each color channel for
+/// style-aware grouping, so near-identical colors still fall in the same
+/// bucket.
+const STYLE_COLOR_BUCKET: u16 = 32;
+
+/// A coarse (color, width) style bucket used to keep visually distinct
+/// rules (e.g. a thin gridline vs. a thick section divider) from being
+/// snapped or joined together.
+type StyleKey = (u8, u8, u8, u8, i32);
+
+/// Quantizes an edge's color and width into a [`StyleKey`].
+fn edge_style_key(edge: &Edge) -> StyleKey {
+    let quantize_channel = |channel: u8| (channel as u16 / STYLE_COLOR_BUCKET) as u8;
+    (
+        quantize_channel(edge.color.red()),
+        quantize_channel(edge.color.green()),
+        quantize_channel(edge.color.blue()),
+        quantize_channel(edge.color.alpha()),
+        (edge.width.into_inner() / STYLE_WIDTH_BUCKET).round() as i32,
+    )
+}
+
+/// Splits `edges` into groups sharing the same [`edge_style_key`].
+fn partition_by_style(edges: Vec<Edge>) -> Vec<Vec<Edge>> {
+    let mut buckets: HashMap<StyleKey, Vec<Edge>> = HashMap::new();
+    for edge in edges {
+        buckets.entry(edge_style_key(&edge)).or_default().push(edge);
+    }
+    buckets.into_values().collect()
+}
+
+/// Picks the most common exact `(color, width)` pairing among `edges`,
+/// so a style bucket's merged edges reflect the style most of its
+/// constituent segments actually had rather than an arbitrary survivor's.
+///
+/// Panics if `edges` is empty; callers only invoke this on non-empty
+/// style buckets.
+fn dominant_edge_style(edges: &[Edge]) -> (PdfColor, OrderedFloat<f32>) {
+    let mut counts: HashMap<(u8, u8, u8, u8, OrderedFloat<f32>), usize> = HashMap::new();
+    let mut colors: HashMap<(u8, u8, u8, u8, OrderedFloat<f32>), PdfColor> = HashMap::new();
+    for edge in edges {
+        let key = (
+            edge.color.red(),
+            edge.color.green(),
+            edge.color.blue(),
+            edge.color.alpha(),
+            edge.width,
+        );
+        *counts.entry(key).or_insert(0) += 1;
+        colors.entry(key).or_insert(edge.color);
+    }
+    let (key, _) = counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .expect("style bucket is non-empty");
+    (colors[&key], key.4)
+}
+
+/// Merges edges of a single orientation by snapping, stitching dashes, and
+/// joining.
 ///
-/// First snaps nearby edges together, then joins overlapping edges.
+/// First snaps nearby edges together, then stitches runs of dashed or
+/// dotted segments into single edges, then joins overlapping edges.
 ///
 /// # Arguments
 ///
@@ -327,15 +754,71 @@ fn join_edge_group(
 /// * `orient` - The orientation of all edges.
 /// * `snap_tolerance` - Tolerance for snapping edges together.
 /// * `join_tolerance` - Tolerance for joining overlapping edges.
+/// * `dash_max_gap` - Maximum gap between dash/dot segments to stitch them
+///   together; 0 disables dash stitching.
+/// * `min_dash_count` - Minimum number of segments required before a run
+///   is treated as a dashed or dotted rule.
+/// * `respect_edge_style` - When `true`, edges are first partitioned into
+///   quantized (color, width) buckets and only snapped/joined within a
+///   bucket, so a thin light gridline near a thick dark border isn't
+///   averaged into it; the merged edges then carry the bucket's dominant
+///   exact style instead of an arbitrary survivor's.
 ///
 /// # Returns
 ///
 /// A vector of merged edges.
 fn merge_one_kind_edges(
+    edges: Vec<Edge>,
+    orient: Orientation,
+    snap_tolerance: OrderedFloat<f32>,
+    join_tolerance: OrderedFloat<f32>,
+    dash_max_gap: OrderedFloat<f32>,
+    min_dash_count: usize,
+    respect_edge_style: bool,
+) -> Vec<Edge> {
+    if !respect_edge_style {
+        return merge_one_kind_edges_in_style_group(
+            edges,
+            orient,
+            snap_tolerance,
+            join_tolerance,
+            dash_max_gap,
+            min_dash_count,
+            None,
+        );
+    }
+    partition_by_style(edges)
+        .into_iter()
+        .flat_map(|group| {
+            let dominant_style = dominant_edge_style(&group);
+            merge_one_kind_edges_in_style_group(
+                group,
+                orient,
+                snap_tolerance,
+                join_tolerance,
+                dash_max_gap,
+                min_dash_count,
+                Some(dominant_style),
+            )
+        })
+        .collect()
+}
+
+/// Runs the snap/stitch/join pipeline over edges already known to share a
+/// single orientation and (when [`merge_one_kind_edges`] partitions by
+/// style) a single style bucket.
+///
+/// When `style_override` is `Some`, every output edge's color and width
+/// are overwritten with it; this is how the dominant style of a style
+/// bucket is carried onto its merged edges.
+fn merge_one_kind_edges_in_style_group(
     mut edges: Vec<Edge>,
     orient: Orientation,
     snap_tolerance: OrderedFloat<f32>,
     join_tolerance: OrderedFloat<f32>,
+    dash_max_gap: OrderedFloat<f32>,
+    min_dash_count: usize,
+    style_override: Option<(PdfColor, OrderedFloat<f32>)>,
 ) -> Vec<Edge> {
     let get_prop: fn(&Edge) -> OrderedFloat<f32> = match orient {
         Orientation::Vertical => |e| e.x1,
@@ -350,14 +833,21 @@ fn merge_one_kind_edges(
         edges = snap_objects(edges, attr, snap_tolerance);
     }
     edges.sort_by_key(&get_prop);
-    edges
+    let mut merged: Vec<Edge> = edges
         .chunk_by(|e1, e2| get_prop(e1) == get_prop(e2))
         .map(|slice| slice.to_vec())
         .flat_map(|group| {
-            let joined = join_edge_group(group, orient, join_tolerance);
-            joined
+            let stitched = stitch_dashes(group, orient, dash_max_gap, min_dash_count);
+            join_edge_group(stitched, orient, join_tolerance)
         })
-        .collect()
+        .collect();
+    if let Some((color, width)) = style_override {
+        for edge in &mut merged {
+            edge.color = color;
+            edge.width = width;
+        }
+    }
+    merged
 }
 
 /// Merges both horizontal and vertical edges with specified tolerances.
@@ -369,6 +859,12 @@ fn merge_one_kind_edges(
 /// * `snap_y_tolerance` - Y-axis tolerance for snapping horizontal edges.
 /// * `join_x_tolerance` - X-axis tolerance for joining horizontal edges.
 /// * `join_y_tolerance` - Y-axis tolerance for joining vertical edges.
+/// * `dash_max_gap` - Maximum gap between dash/dot segments to stitch them
+///   together; 0 disables dash stitching.
+/// * `min_dash_count` - Minimum number of segments required before a run
+///   is treated as a dashed or dotted rule.
+/// * `respect_edge_style` - When `true`, edges are only snapped/joined
+///   against others of a similar color and width.
 ///
 /// # Returns
 ///
@@ -379,6 +875,9 @@ pub(crate) fn merge_edges(
     snap_y_tolerance: OrderedFloat<f32>,
     join_x_tolerance: OrderedFloat<f32>,
     join_y_tolerance: OrderedFloat<f32>,
+    dash_max_gap: OrderedFloat<f32>,
+    min_dash_count: usize,
+    respect_edge_style: bool,
 ) -> HashMap<Orientation, Vec<Edge>> {
     HashMap::from([
         (
@@ -388,6 +887,9 @@ pub(crate) fn merge_edges(
                 Orientation::Vertical,
                 snap_x_tolerance,
                 join_y_tolerance,
+                dash_max_gap,
+                min_dash_count,
+                respect_edge_style,
             ),
         ),
         (
@@ -397,6 +899,9 @@ pub(crate) fn merge_edges(
                 Orientation::Horizontal,
                 snap_y_tolerance,
                 join_x_tolerance,
+                dash_max_gap,
+                min_dash_count,
+                respect_edge_style,
             ),
         ),
     ])
@@ -538,55 +1043,85 @@ pub(crate) fn make_edges(
         tf_settings.horizontal_strategy,
         tf_settings.vertical_strategy,
     );
-    if h_strat == StrategyType::Text || v_strat == StrategyType::Text {
+    if h_strat.contains(StrategyType::Text)
+        || v_strat.contains(StrategyType::Text)
+        || h_strat.contains(StrategyType::Projection)
+        || v_strat.contains(StrategyType::Projection)
+    {
         let words = WordExtractor::new(&tf_settings.text_settings).extract_words(&objects.chars);
-        if h_strat == StrategyType::Text {
-            edges
-                .get_mut(&Orientation::Horizontal)
-                .unwrap()
-                .extend(words_to_edges_h(&words, tf_settings.min_words_horizontal));
+        if h_strat.contains(StrategyType::Text) {
+            edges.get_mut(&Orientation::Horizontal).unwrap().extend(words_to_edges_h(
+                &words,
+                tf_settings.min_words_horizontal,
+                *snap_y_tol,
+            ));
+        }
+        if v_strat.contains(StrategyType::Text) {
+            edges.get_mut(&Orientation::Vertical).unwrap().extend(words_to_edges_v(
+                &words,
+                tf_settings.min_words_vertical,
+                *snap_x_tol,
+            ));
         }
-        if v_strat == StrategyType::Text {
-            edges
-                .get_mut(&Orientation::Vertical)
-                .unwrap()
-                .extend(words_to_edges_v(&words, tf_settings.min_words_vertical));
+        if h_strat.contains(StrategyType::Projection) {
+            edges.get_mut(&Orientation::Horizontal).unwrap().extend(words_to_edges_h_projection(
+                &words,
+                *tf_settings.min_gutter_width,
+                tf_settings.gutter_coverage_threshold,
+            ));
+        }
+        if v_strat.contains(StrategyType::Projection) {
+            edges.get_mut(&Orientation::Vertical).unwrap().extend(words_to_edges_v_projection(
+                &words,
+                *tf_settings.min_gutter_width,
+                tf_settings.gutter_coverage_threshold,
+            ));
         }
     }
 
-    if ((h_strat | 0b11u8) != 0) || ((v_strat | 0b11u8) != 0) {
-        // 0b11: Lines or LinesStrict
+    // Lines or LinesStrict.
+    let h_lines_strat =
+        h_strat.contains(StrategyType::Lines) || h_strat.contains(StrategyType::LinesStrict);
+    let v_lines_strat =
+        v_strat.contains(StrategyType::Lines) || v_strat.contains(StrategyType::LinesStrict);
+
+    if h_lines_strat || v_lines_strat {
+        // Curves are subdivided into a polyline within the tighter of the
+        // two snap tolerances, so a flattened segment that's "flat enough"
+        // to keep can't itself be farther off-axis than the axis check below
+        // would already tolerate.
+        let flatten_tolerance = cmp::min(snap_x_tol, snap_y_tol).into_inner();
+
         for line in lines {
-            if line.line_type == LineType::Straight {
-                let (p1, p2) = (line.points[0], line.points[1]);
-                if ((v_strat | 0b11u8) != 0) && ((p1.0 - p2.0).abs() < snap_x_tol.into_inner()) {
+            let flattened = line.flatten(flatten_tolerance);
+            for segment in flattened.points.windows(2) {
+                let (p1, p2) = (segment[0], segment[1]);
+                if v_lines_strat && ((p1.0 - p2.0).abs() < snap_x_tol.into_inner()) {
                     edges.get_mut(&Orientation::Vertical).unwrap().push(Edge {
                         orientation: Orientation::Vertical,
                         x1: p1.0,
                         y1: cmp::min(p1.1, p2.1),
                         x2: p1.0,
                         y2: cmp::max(p1.1, p2.1),
-                        width: line.width,
-                        color: line.color,
+                        width: flattened.width,
+                        color: flattened.color,
                     });
-                } else if ((h_strat | 0b11u8) != 0)
-                    && ((p1.1 - p2.1).abs() < snap_y_tol.into_inner())
-                {
+                } else if h_lines_strat && ((p1.1 - p2.1).abs() < snap_y_tol.into_inner()) {
                     edges.get_mut(&Orientation::Horizontal).unwrap().push(Edge {
                         orientation: Orientation::Horizontal,
                         x1: cmp::min(p1.0, p2.0),
                         y1: p1.1,
                         x2: cmp::max(p1.0, p2.0),
                         y2: p1.1,
-                        width: line.width,
-                        color: line.color,
+                        width: flattened.width,
+                        color: flattened.color,
                     })
                 }
             }
         }
 
         for rect in rects {
-            if ((v_strat | 0b11u8) != 0) && (rect.bbox.2 - rect.bbox.0 < snap_x_tol) {
+            if v_lines_strat && (rect.bbox.2 - rect.bbox.0 < snap_x_tol) {
                 let x = (rect.bbox.0 + rect.bbox.2) / 2.0;
                 edges.get_mut(&Orientation::Vertical).unwrap().push(Edge {
                     orientation: Orientation::Vertical,
@@ -597,7 +1132,7 @@ pub(crate) fn make_edges(
                     width: rect.bbox.2 - rect.bbox.0,
                     color: rect.fill_color,
                 });
-            } else if ((h_strat | 0b11u8) != 0) && (rect.bbox.3 - rect.bbox.1 < snap_y_tol) {
+            } else if h_lines_strat && (rect.bbox.3 - rect.bbox.1 < snap_y_tol) {
                 let y = (rect.bbox.1 + rect.bbox.3) / 2.0;
                 edges.get_mut(&Orientation::Horizontal).unwrap().push(Edge {
                     orientation: Orientation::Horizontal,
@@ -609,7 +1144,7 @@ pub(crate) fn make_edges(
                     color: rect.fill_color,
                 })
             } else {
-                if h_strat == StrategyType::Lines {
+                if h_strat.contains(StrategyType::Lines) {
                     edges.get_mut(&Orientation::Horizontal).unwrap().push(Edge {
                         orientation: Orientation::Horizontal,
                         x1: rect.bbox.0,
@@ -629,7 +1164,7 @@ pub(crate) fn make_edges(
                         color: rect.stroke_color,
                     });
                 }
-                if v_strat == StrategyType::Lines {
+                if v_strat.contains(StrategyType::Lines) {
                     edges.get_mut(&Orientation::Vertical).unwrap().push(Edge {
                         orientation: Orientation::Vertical,
                         x1: rect.bbox.0,
@@ -663,6 +1198,105 @@ mod tests {
     use ordered_float::OrderedFloat;
     use pdfium_render::prelude::PdfColor;
 
+    fn make_test_line(line_type: LineType, points: Vec<Point>) -> Line {
+        Line {
+            line_type,
+            points,
+            color: PdfColor::new(0, 0, 0, 255),
+            width: OrderedFloat(1.0),
+        }
+    }
+
+    fn pt(x: f32, y: f32) -> Point {
+        (OrderedFloat(x), OrderedFloat(y))
+    }
+
+    #[test]
+    fn test_make_edges_splits_multi_segment_straight_line_into_segments() {
+        // An L-shaped polyline: a vertical run then a horizontal run,
+        // chained as a single straight `Line`.
+        let line = make_test_line(
+            LineType::Straight,
+            vec![pt(0.0, 0.0), pt(0.0, 10.0), pt(10.0, 10.0)],
+        );
+        let objects = Objects {
+            rects: Vec::new(),
+            lines: vec![line],
+            chars: Vec::new(),
+        };
+        let mut settings = TfSettings::default();
+        settings.vertical_strategy = StrategySet::from(StrategyType::Lines);
+        settings.horizontal_strategy = StrategySet::from(StrategyType::Lines);
+
+        let edges = make_edges(&objects, Rc::new(settings));
+        assert_eq!(edges[&Orientation::Vertical].len(), 1);
+        assert_eq!(edges[&Orientation::Horizontal].len(), 1);
+        assert_eq!(edges[&Orientation::Vertical][0].y2, OrderedFloat(10.0));
+        assert_eq!(edges[&Orientation::Horizontal][0].x2, OrderedFloat(10.0));
+    }
+
+    #[test]
+    fn test_make_edges_no_longer_drops_curved_lines() {
+        // Control points sit exactly on the chord, so this flattens to a
+        // single vertical segment at any positive tolerance. Before
+        // flattening was wired in, `make_edges` silently skipped anything
+        // that wasn't already `LineType::Straight`.
+        let line = make_test_line(
+            LineType::Curve,
+            vec![pt(0.0, 0.0), pt(0.0, 3.0), pt(0.0, 7.0), pt(0.0, 10.0)],
+        );
+        let objects = Objects {
+            rects: Vec::new(),
+            lines: vec![line],
+            chars: Vec::new(),
+        };
+        let mut settings = TfSettings::default();
+        settings.vertical_strategy = StrategySet::from(StrategyType::Lines);
+        settings.horizontal_strategy = StrategySet::EMPTY;
+
+        let edges = make_edges(&objects, Rc::new(settings));
+        assert_eq!(edges[&Orientation::Vertical].len(), 1);
+        assert_eq!(edges[&Orientation::Vertical][0].y1, OrderedFloat(0.0));
+        assert_eq!(edges[&Orientation::Vertical][0].y2, OrderedFloat(10.0));
+    }
+
+    fn make_test_char(unicode_char: &str, x1: f32, x2: f32) -> Char {
+        Char {
+            unicode_char: Some(unicode_char.to_string()),
+            bbox: (OrderedFloat(x1), OrderedFloat(0.0), OrderedFloat(x2), OrderedFloat(10.0)),
+            rotation_degrees: OrderedFloat(0.0),
+            upright: true,
+            font_size: OrderedFloat(10.0),
+            font_name: None,
+            fill_color: PdfColor::new(0, 0, 0, 255),
+            text_matrix: (1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+        }
+    }
+
+    #[test]
+    fn test_make_edges_dispatches_to_projection_strategy() {
+        // Two words separated by a wide whitespace gutter, with only the
+        // `Projection` strategy selected: `make_edges` must reach
+        // `words_to_edges_v_projection` itself rather than requiring a
+        // caller to invoke it directly.
+        let objects = Objects {
+            rects: Vec::new(),
+            lines: Vec::new(),
+            chars: vec![
+                make_test_char("a", 0.0, 10.0),
+                make_test_char("b", 30.0, 40.0),
+            ],
+        };
+        let mut settings = TfSettings::default();
+        settings.vertical_strategy = StrategySet::from(StrategyType::Projection);
+        settings.horizontal_strategy = StrategySet::EMPTY;
+
+        let edges = make_edges(&objects, Rc::new(settings));
+        let vertical = &edges[&Orientation::Vertical];
+        assert_eq!(vertical.len(), 1);
+        assert_eq!(vertical[0].x1, OrderedFloat(20.0));
+    }
+
     fn make_test_edge(x1: f32, y1: f32, x2: f32, y2: f32) -> Edge {
         Edge {
             orientation: Orientation::Vertical,
@@ -690,6 +1324,227 @@ mod tests {
         assert_eq!(result[0].x1, OrderedFloat(6.0));
     }
 
+    fn make_test_word(x1: f32, y1: f32, x2: f32, y2: f32) -> Word {
+        Word {
+            text: "word".to_string(),
+            bbox: (OrderedFloat(x1), OrderedFloat(y1), OrderedFloat(x2), OrderedFloat(y2)),
+            rotation_degrees: OrderedFloat(0.0),
+            extra_attrs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_coverage_histogram_counts_overlapping_spans() {
+        let spans = vec![
+            (OrderedFloat(0.0), OrderedFloat(2.0)),
+            (OrderedFloat(1.0), OrderedFloat(4.0)),
+        ];
+        let histogram = coverage_histogram(&spans, 0.0, 5.0);
+        assert_eq!(histogram, vec![1, 2, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_find_gutters_reports_wide_low_coverage_runs() {
+        let histogram = vec![1, 0, 0, 0, 1, 0, 1];
+        let gutters = find_gutters(&histogram, 0.0, 0, 2.0);
+        assert_eq!(gutters, vec![(1.0, 4.0)]);
+    }
+
+    #[test]
+    fn test_find_gutters_respects_coverage_threshold() {
+        let histogram = vec![2, 1, 1, 2];
+        assert!(find_gutters(&histogram, 0.0, 0, 2.0).is_empty());
+        let gutters = find_gutters(&histogram, 0.0, 1, 2.0);
+        assert_eq!(gutters, vec![(1.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_words_to_edges_v_projection_finds_whitespace_gutter() {
+        let words = vec![
+            make_test_word(0.0, 0.0, 10.0, 10.0),
+            make_test_word(20.0, 0.0, 30.0, 10.0),
+        ];
+
+        let edges = words_to_edges_v_projection(&words, OrderedFloat(5.0), 0);
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].orientation, Orientation::Vertical);
+        assert_eq!(edges[0].x1, OrderedFloat(15.0));
+        assert_eq!(edges[0].y1, OrderedFloat(0.0));
+        assert_eq!(edges[0].y2, OrderedFloat(10.0));
+    }
+
+    #[test]
+    fn test_words_to_edges_v_projection_ignores_narrow_gaps() {
+        let words = vec![
+            make_test_word(0.0, 0.0, 10.0, 10.0),
+            make_test_word(12.0, 0.0, 22.0, 10.0),
+        ];
+
+        let edges = words_to_edges_v_projection(&words, OrderedFloat(5.0), 0);
+        assert!(edges.is_empty());
+    }
+
+    #[test]
+    fn test_is_roughly_uniform_accepts_tight_cluster_rejects_wide_spread() {
+        assert!(is_roughly_uniform(&[2.0, 2.1, 1.9], 1.0));
+        assert!(!is_roughly_uniform(&[2.0, 10.0], 1.0));
+    }
+
+    fn make_test_dash_run(starts_and_lengths: &[(f32, f32)]) -> Vec<Edge> {
+        starts_and_lengths
+            .iter()
+            .map(|&(start, length)| make_test_edge(0.0, start, 0.0, start + length))
+            .collect()
+    }
+
+    #[test]
+    fn test_is_dash_run_accepts_regular_dashes_rejects_irregular_gaps() {
+        let regular = make_test_dash_run(&[(0.0, 2.0), (3.0, 2.0), (6.0, 2.0), (9.0, 2.0)]);
+        assert!(is_dash_run(&regular, Orientation::Vertical, OrderedFloat(2.0)));
+
+        let irregular_gaps = make_test_dash_run(&[(0.0, 2.0), (3.0, 2.0), (10.0, 2.0)]);
+        assert!(!is_dash_run(
+            &irregular_gaps,
+            Orientation::Vertical,
+            OrderedFloat(10.0)
+        ));
+
+        let gap_too_wide = make_test_dash_run(&[(0.0, 2.0), (5.0, 2.0)]);
+        assert!(!is_dash_run(
+            &gap_too_wide,
+            Orientation::Vertical,
+            OrderedFloat(2.0)
+        ));
+    }
+
+    #[test]
+    fn test_stitch_dashes_merges_regular_run_into_one_edge() {
+        let segments = make_test_dash_run(&[(0.0, 2.0), (3.0, 2.0), (6.0, 2.0), (9.0, 2.0)]);
+
+        let stitched = stitch_dashes(segments, Orientation::Vertical, OrderedFloat(2.0), 4);
+
+        assert_eq!(stitched.len(), 1);
+        assert_eq!(stitched[0].y1, OrderedFloat(0.0));
+        assert_eq!(stitched[0].y2, OrderedFloat(11.0));
+    }
+
+    #[test]
+    fn test_stitch_dashes_leaves_run_below_min_count_untouched() {
+        let segments = make_test_dash_run(&[(0.0, 2.0), (3.0, 2.0), (6.0, 2.0)]);
+
+        let stitched = stitch_dashes(segments.clone(), Orientation::Vertical, OrderedFloat(2.0), 4);
+
+        assert_eq!(stitched.len(), segments.len());
+    }
+
+    #[test]
+    fn test_stitch_dashes_disabled_when_dash_max_gap_is_zero() {
+        let segments = make_test_dash_run(&[(0.0, 2.0), (3.0, 2.0), (6.0, 2.0), (9.0, 2.0)]);
+
+        let stitched = stitch_dashes(segments.clone(), Orientation::Vertical, OrderedFloat(0.0), 4);
+
+        assert_eq!(stitched.len(), segments.len());
+    }
+
+    fn make_test_edge_with_style(
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        color: PdfColor,
+        width: f32,
+    ) -> Edge {
+        Edge {
+            orientation: Orientation::Vertical,
+            x1: OrderedFloat(x1),
+            y1: OrderedFloat(y1),
+            x2: OrderedFloat(x2),
+            y2: OrderedFloat(y2),
+            width: OrderedFloat(width),
+            color,
+        }
+    }
+
+    #[test]
+    fn test_edge_style_key_buckets_similar_widths_and_colors_together() {
+        let black = PdfColor::new(0, 0, 0, 255);
+        let thin_a = make_test_edge_with_style(0.0, 0.0, 0.0, 10.0, black, 1.0);
+        let thin_b = make_test_edge_with_style(0.0, 0.0, 0.0, 10.0, black, 1.2);
+        let thick = make_test_edge_with_style(0.0, 0.0, 0.0, 10.0, black, 3.0);
+
+        assert_eq!(edge_style_key(&thin_a), edge_style_key(&thin_b));
+        assert_ne!(edge_style_key(&thin_a), edge_style_key(&thick));
+    }
+
+    #[test]
+    fn test_partition_by_style_groups_by_quantized_color_and_width() {
+        let thin_black = make_test_edge_with_style(0.0, 0.0, 0.0, 10.0, PdfColor::new(0, 0, 0, 255), 1.0);
+        let thick_gray =
+            make_test_edge_with_style(5.0, 0.0, 5.0, 10.0, PdfColor::new(200, 200, 200, 255), 3.0);
+
+        let buckets = partition_by_style(vec![thin_black, thick_gray]);
+        assert_eq!(buckets.len(), 2);
+    }
+
+    #[test]
+    fn test_dominant_edge_style_picks_most_common_exact_pair() {
+        let color = PdfColor::new(0, 0, 0, 255);
+        let edges = vec![
+            make_test_edge_with_style(0.0, 0.0, 0.0, 10.0, color, 1.0),
+            make_test_edge_with_style(0.0, 0.0, 0.0, 10.0, color, 1.0),
+            make_test_edge_with_style(0.0, 0.0, 0.0, 10.0, color, 1.2),
+        ];
+
+        let (dominant_color, dominant_width) = dominant_edge_style(&edges);
+        assert_eq!(dominant_width, OrderedFloat(1.0));
+        assert_eq!(
+            (
+                dominant_color.red(),
+                dominant_color.green(),
+                dominant_color.blue(),
+                dominant_color.alpha()
+            ),
+            (0, 0, 0, 255)
+        );
+    }
+
+    #[test]
+    fn test_merge_edges_respects_edge_style_keeps_distinct_rules_separate() {
+        let thin_black =
+            make_test_edge_with_style(10.0, 0.0, 10.0, 100.0, PdfColor::new(0, 0, 0, 255), 0.5);
+        let thick_gray =
+            make_test_edge_with_style(10.3, 0.0, 10.3, 100.0, PdfColor::new(200, 200, 200, 255), 3.0);
+
+        let edges = HashMap::from([
+            (Orientation::Vertical, vec![thin_black, thick_gray]),
+            (Orientation::Horizontal, vec![]),
+        ]);
+
+        let merged_without_style = merge_edges(
+            edges.clone(),
+            OrderedFloat(1.0),
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(1.0),
+            OrderedFloat(0.0),
+            0,
+            false,
+        );
+        assert_eq!(merged_without_style[&Orientation::Vertical].len(), 1);
+
+        let merged_with_style = merge_edges(
+            edges,
+            OrderedFloat(1.0),
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(1.0),
+            OrderedFloat(0.0),
+            0,
+            true,
+        );
+        assert_eq!(merged_with_style[&Orientation::Vertical].len(), 2);
+    }
+
     #[test]
     fn test_edge_merging() {
         let project_root = env!("CARGO_MANIFEST_DIR");
@@ -717,6 +1572,9 @@ mod tests {
             OrderedFloat(3.0),
             OrderedFloat(3.0),
             OrderedFloat(3.0),
+            OrderedFloat(0.0),
+            0,
+            false,
         );
         assert_eq!(count(&merged), 46);
 
@@ -726,6 +1584,9 @@ mod tests {
             OrderedFloat(3.0),
             OrderedFloat(3.0),
             OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            0,
+            false,
         );
         assert_eq!(count(&merged), 52);
 
@@ -735,6 +1596,9 @@ mod tests {
             OrderedFloat(3.0),
             OrderedFloat(3.0),
             OrderedFloat(3.0),
+            OrderedFloat(0.0),
+            0,
+            false,
         );
         assert_eq!(count(&merged), 47);
 
@@ -744,6 +1608,9 @@ mod tests {
             OrderedFloat(0.0001),
             OrderedFloat(3.0),
             OrderedFloat(3.0),
+            OrderedFloat(0.0),
+            0,
+            false,
         );
         assert_eq!(count(&merged), 88);
     }
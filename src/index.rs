@@ -0,0 +1,181 @@
+use crate::tables::Table;
+use pyo3::prelude::*;
+use std::collections::BTreeMap;
+
+/// The row index of a table row, as used by [`StringIndex`].
+pub type RowId = usize;
+
+/// A sorted index mapping each distinct string key in a table column to the
+/// row indices containing it, supporting both exact and prefix lookups.
+///
+/// Backed by a plain `BTreeMap`, giving `O(log n)` exact lookups and
+/// sorted-range prefix queries. This is not an FST (finite-state
+/// transducer, à la BurntSushi's `fst` crate) — it doesn't share
+/// transitions between keys with common prefixes, so it won't match an
+/// FST's sub-linear-in-key-length lookups or its compact, bit-packed
+/// storage for very large key sets. Building a real FST index is tracked
+/// separately pending an `fst` dependency.
+#[pyclass]
+#[derive(Debug, Clone, Default)]
+pub struct StringIndex {
+    entries: BTreeMap<String, Vec<RowId>>,
+}
+
+impl StringIndex {
+    /// Builds an index from `(key, row)` pairs, sorting by key first before
+    /// grouping row ids under each distinct key.
+    pub fn build(entries: impl IntoIterator<Item = (String, RowId)>) -> Self {
+        let mut sorted: Vec<(String, RowId)> = entries.into_iter().collect();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut map: BTreeMap<String, Vec<RowId>> = BTreeMap::new();
+        for (key, row) in sorted {
+            map.entry(key).or_default().push(row);
+        }
+        Self { entries: map }
+    }
+}
+
+#[pymethods]
+impl StringIndex {
+    /// Returns the row indices containing `key` exactly, or an empty list
+    /// if `key` isn't present.
+    fn get(&self, key: &str) -> Vec<RowId> {
+        self.entries.get(key).cloned().unwrap_or_default()
+    }
+
+    /// Returns `(key, row_ids)` for every distinct key starting with
+    /// `prefix`, in sorted key order.
+    fn range(&self, prefix: &str) -> Vec<(String, Vec<RowId>)> {
+        let start = prefix.to_string();
+        match prefix_range_end(prefix) {
+            Some(end) => self
+                .entries
+                .range(start..end)
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+            None => self
+                .entries
+                .range(start..)
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        }
+    }
+
+    /// Returns the number of distinct keys in the index.
+    fn __len__(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// Computes the exclusive upper bound of the range of strings starting
+/// with `prefix`, by incrementing its last Unicode scalar value; returns
+/// `None` when `prefix` has no finite successor (it's empty, or every
+/// trailing character is already the maximum scalar value), meaning the
+/// range is unbounded above.
+fn prefix_range_end(prefix: &str) -> Option<String> {
+    let mut chars: Vec<char> = prefix.chars().collect();
+    while let Some(last) = chars.pop() {
+        // The scalar value after U+D7FF is U+E000 (surrogates are skipped).
+        let next = if last == '\u{D7FF}' {
+            Some('\u{E000}')
+        } else {
+            char::from_u32(last as u32 + 1)
+        };
+        if let Some(next) = next {
+            chars.push(next);
+            return Some(chars.into_iter().collect());
+        }
+    }
+    None
+}
+
+/// Collects `(text, row)` pairs for every non-blank cell in `table`'s
+/// column `col`, reading its rendered grid.
+fn column_entries(table: &Table, col: usize) -> Vec<(String, RowId)> {
+    table
+        .to_grid(false)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(row, cells)| cells.into_iter().nth(col).flatten().map(|text| (text, row)))
+        .collect()
+}
+
+/// Builds a [`StringIndex`] over the string values in `table`'s column
+/// `col`, for fast equality and prefix lookups without scanning every row.
+pub fn build_string_index(table: &Table, col: usize) -> StringIndex {
+    StringIndex::build(column_entries(table, col))
+}
+
+/// Performs an equality join between `left`'s column `left_col` and
+/// `right`'s column `right_col`, indexing `right` first so each left row
+/// only visits the right rows it actually matches instead of scanning all
+/// of `right` for every left row.
+///
+/// Returns `(left_row, right_row)` pairs for every matching value, in
+/// `left`'s row order (and, within a left row, `right`'s row order).
+pub fn equi_join_on_index(
+    left: &Table,
+    left_col: usize,
+    right: &Table,
+    right_col: usize,
+) -> Vec<(RowId, RowId)> {
+    let index = build_string_index(right, right_col);
+
+    column_entries(left, left_col)
+        .into_iter()
+        .flat_map(|(text, left_row)| {
+            index
+                .get(&text)
+                .into_iter()
+                .map(move |right_row| (left_row, right_row))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_index_get_returns_all_rows_for_a_key() {
+        let index = StringIndex::build([
+            ("apple".to_string(), 0),
+            ("banana".to_string(), 1),
+            ("apple".to_string(), 2),
+        ]);
+
+        assert_eq!(index.get("apple"), vec![0, 2]);
+        assert_eq!(index.get("banana"), vec![1]);
+        assert!(index.get("cherry").is_empty());
+        assert_eq!(index.__len__(), 2);
+    }
+
+    #[test]
+    fn test_string_index_range_matches_prefix_only() {
+        let index = StringIndex::build([
+            ("apple".to_string(), 0),
+            ("application".to_string(), 1),
+            ("banana".to_string(), 2),
+        ]);
+
+        let matches = index.range("app");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].0, "apple");
+        assert_eq!(matches[1].0, "application");
+    }
+
+    #[test]
+    fn test_string_index_range_empty_prefix_returns_everything() {
+        let index =
+            StringIndex::build([("a".to_string(), 0), ("b".to_string(), 1)]);
+
+        assert_eq!(index.range("").len(), 2);
+    }
+
+    #[test]
+    fn test_prefix_range_end_increments_last_scalar() {
+        assert_eq!(prefix_range_end("app"), Some("apq".to_string()));
+        assert_eq!(prefix_range_end(""), None);
+    }
+}
@@ -2,24 +2,50 @@ use crate::edges::Edge;
 use crate::objects::*;
 use crate::pages::Page;
 use crate::settings::*;
+use crate::index::StringIndex;
+use crate::stats::ColumnStats;
 use crate::tables::*;
+use crate::words::{Word, WordExtractor};
 use ordered_float::OrderedFloat;
 use pdfium_render::prelude::{PdfDocument, PdfPageIndex, Pdfium, PdfiumError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PySlice};
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::path::Path;
 use std::rc::Rc;
 mod clusters;
+mod density;
 mod edges;
+mod index;
+mod merge;
 mod objects;
+mod ocr;
+mod page_size;
 mod pages;
+mod pdfium_config;
+mod pdfium_pool;
+#[cfg(any(feature = "dynamic-pdf", not(feature = "static-pdf")))]
+mod pdfium_symbols;
+mod render_backend;
+mod render_debug;
+mod search;
 mod settings;
+mod stats;
 mod tables;
 #[cfg(test)]
 mod test_utils;
 mod words;
 
+pub use ocr::{ocr_words_to_chars, page_needs_ocr, OcrEngine, OcrMode, OcrWord};
+pub use page_size::{fast_page_sizes, prefilter_pages_by_size, PageDimensions, PageSizePredicate};
+pub use pdfium_config::PdfiumConfig;
+pub use pdfium_pool::{maybe_run_worker, PdfiumPool};
+pub use render_backend::{
+    cross_validate, BackendDiff, BackendTable, PdfiumBackend, RenderBackend, SubprocessBackend,
+};
+pub use render_debug::DebugLayers;
+
 type PyBbox = (f32, f32, f32, f32);
 
 /// A wrapper around the Pdfium library runtime.
@@ -32,24 +58,30 @@ pub struct PdfiumRuntime {
 }
 #[pymethods]
 impl PdfiumRuntime {
-    /// Creates a new PdfiumRuntime instance by loading the Pdfium library from the specified path.
+    /// Creates a new PdfiumRuntime instance by locating and loading the Pdfium library.
     ///
     /// # Arguments
     ///
-    /// * `path` - The file path to the Pdfium dynamic library.
+    /// * `path` - An explicit file path to the Pdfium dynamic library. When omitted, the
+    ///   library is resolved via `TABLERS_PDFIUM_PATH`, then `PDFIUM_DYNAMIC_LIB_PATH`, then
+    ///   the system search path, then the bundled platform default (see [`PdfiumConfig`]).
     ///
     /// # Returns
     ///
     /// A new `PdfiumRuntime` instance or a Python error if the library fails to load.
     #[new]
-    fn py_new(path: String) -> PyResult<Self> {
-        let bindings = Pdfium::bind_to_library(path).map_err(|e| {
+    #[pyo3(signature = (path=None))]
+    fn py_new(path: Option<String>) -> PyResult<Self> {
+        let config = match path {
+            Some(path) => PdfiumConfig::with_path(path),
+            None => PdfiumConfig::default(),
+        };
+        let pdfium = pdfium_config::init_pdfium(&config).map_err(|e| {
             PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
                 "Failed to bind Pdfium: {:?}",
                 e
             ))
         })?;
-        let pdfium = Pdfium::new(bindings);
         Ok(Self {
             inner: Rc::new(pdfium),
         })
@@ -112,8 +144,12 @@ impl PdfiumRuntime {
 /// Contains the Pdfium reference and the actual PDF document.
 /// The document is wrapped in an Option to support closing.
 struct DocumentInner {
-    _pdfium: Rc<Pdfium>,
+    pdfium: Rc<Pdfium>,
     doc: Option<PdfDocument<'static>>, // None means closed
+    /// Lazily populated cache of resolved `/PageLabels` labels, keyed by
+    /// zero-based physical page index, so repeated `Page.label` lookups
+    /// don't re-resolve the number tree.
+    page_label_cache: RefCell<HashMap<usize, String>>,
 }
 
 /// Represents an opened PDF document.
@@ -181,8 +217,9 @@ impl Document {
 
         Ok(Self {
             inner: Rc::new(RefCell::new(DocumentInner {
-                _pdfium: pdfium,
+                pdfium,
                 doc: Some(doc_static),
+                page_label_cache: RefCell::new(HashMap::new()),
             })),
         })
     }
@@ -255,6 +292,144 @@ impl Document {
         })
     }
 
+    /// Returns the total number of pages in the document.
+    ///
+    /// Equivalent to `page_count()`, provided so `len(doc)` works as expected.
+    fn __len__(&self) -> PyResult<usize> {
+        self.page_count()
+    }
+
+    /// Supports `doc[idx]` and `doc[start:stop:step]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - A non-negative index, a negative index counted from the
+    ///   end, or a `slice`.
+    ///
+    /// # Returns
+    ///
+    /// A single `Page` for an integer index, or a `list[Page]` for a slice.
+    /// Raises `IndexError` for an out-of-range integer index; an
+    /// out-of-range slice yields an empty list, matching Python sequence
+    /// semantics.
+    fn __getitem__(&self, py: Python<'_>, index: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        let page_count = self.page_count()?;
+
+        if let Ok(slice) = index.downcast::<PySlice>() {
+            let indices = slice.indices(page_count as isize)?;
+            let mut pages = Vec::new();
+            let mut i = indices.start;
+            if indices.step > 0 {
+                while i < indices.stop {
+                    pages.push(self.get_page(i as usize)?);
+                    i += indices.step;
+                }
+            } else {
+                while i > indices.stop {
+                    pages.push(self.get_page(i as usize)?);
+                    i += indices.step;
+                }
+            }
+            return Ok(PyList::new(py, pages)?.into_any().unbind());
+        }
+
+        let idx: isize = index.extract()?;
+        let resolved = if idx < 0 {
+            idx + page_count as isize
+        } else {
+            idx
+        };
+        if resolved < 0 || resolved as usize >= page_count {
+            return Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
+                "Page index {} out of range (0..{})",
+                idx, page_count
+            )));
+        }
+        Ok(Py::new(py, self.get_page(resolved as usize)?)?.into_any())
+    }
+
+    /// Returns the document's bookmark (outline/table-of-contents) tree.
+    ///
+    /// # Returns
+    ///
+    /// A list of top-level `BookmarkNode`s, each carrying its nested
+    /// children, or a Python error if the document is closed.
+    fn outline(&self) -> PyResult<Vec<BookmarkNode>> {
+        let inner = self.inner.borrow();
+        let doc = inner.doc.as_ref().ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Document is closed")
+        })?;
+        Ok(build_bookmark_siblings(doc.bookmarks().root(), 0))
+    }
+
+    /// Returns the document's encryption permission flags.
+    ///
+    /// # Returns
+    ///
+    /// A `DocumentPermissions` reflecting what the document's owner password
+    /// (if any) permits, or a Python error if the document is closed.
+    fn permissions(&self) -> PyResult<DocumentPermissions> {
+        let inner = self.inner.borrow();
+        let doc = inner.doc.as_ref().ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Document is closed")
+        })?;
+        let permissions = doc.permissions();
+        Ok(DocumentPermissions {
+            can_print: permissions.can_print(),
+            can_print_high_quality: permissions.can_print_high_quality(),
+            can_copy: permissions.can_copy(),
+            can_modify: permissions.can_modify(),
+            can_annotate: permissions.can_add_or_modify_text_annotations(),
+            can_fill_forms: permissions.can_fill_existing_form_fields(),
+            can_assemble_document: permissions.can_assemble_document(),
+        })
+    }
+
+    /// Returns whether the document's permission flags allow extracting
+    /// text/graphics content, following pdfminer.six's handling of
+    /// `PDFTextExtractionNotAllowed` so callers can choose to respect it.
+    fn can_extract_text(&self) -> PyResult<bool> {
+        let inner = self.inner.borrow();
+        let doc = inner.doc.as_ref().ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Document is closed")
+        })?;
+        Ok(doc.permissions().can_extract_text_and_graphics())
+    }
+
+    /// Returns the document's standard metadata fields as a dict.
+    ///
+    /// # Returns
+    ///
+    /// A dict with keys `Title`, `Author`, `Subject`, `Keywords`, `Creator`,
+    /// `Producer`, `CreationDate`, `ModDate` (each `None` if absent), plus
+    /// `version` giving the PDF specification version the document declares.
+    fn metadata(&self) -> PyResult<Py<PyDict>> {
+        let inner = self.inner.borrow();
+        let doc = inner.doc.as_ref().ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Document is closed")
+        })?;
+        let metadata = doc.metadata();
+
+        let get = |tag: pdfium_render::prelude::PdfDocumentMetadataTagType| {
+            metadata.get(tag).map(|entry| entry.value().to_string())
+        };
+        use pdfium_render::prelude::PdfDocumentMetadataTagType::*;
+
+        Python::attach(|py| {
+            let res = PyDict::new(py);
+            res.set_item("Title", get(Title))?;
+            res.set_item("Author", get(Author))?;
+            res.set_item("Subject", get(Subject))?;
+            res.set_item("Keywords", get(Keywords))?;
+            res.set_item("Creator", get(Creator))?;
+            res.set_item("Producer", get(Producer))?;
+            res.set_item("CreationDate", get(CreationDate))?;
+            res.set_item("ModDate", get(ModificationDate))?;
+            res.set_item("version", format!("{:?}", doc.version()))?;
+            Ok(res.unbind())
+        })
+    }
+
     /// Returns an iterator over pages (memory efficient for large PDFs)
     fn pages(&self) -> PyResult<PyPageIterator> {
         self.__iter__()
@@ -316,6 +491,169 @@ impl Document {
     }
 }
 
+/// A single node in a `Document`'s bookmark (outline) tree.
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct BookmarkNode {
+    /// The bookmark's display title.
+    pub title: String,
+    /// The nesting depth of this bookmark, starting at `0` for top-level
+    /// entries.
+    pub level: usize,
+    /// The zero-based page index this bookmark points to, resolved from its
+    /// destination, or `None` if it has no resolvable destination.
+    pub page_idx: Option<usize>,
+    /// This bookmark's children, in document order.
+    pub children: Vec<BookmarkNode>,
+}
+
+#[pymethods]
+impl BookmarkNode {
+    /// Returns the bookmark's display title.
+    #[getter]
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the nesting depth of this bookmark.
+    #[getter]
+    fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Returns the destination page index, if resolvable.
+    #[getter]
+    fn page_idx(&self) -> Option<usize> {
+        self.page_idx
+    }
+
+    /// Returns this bookmark's children.
+    #[getter]
+    fn children(&self) -> Vec<BookmarkNode> {
+        self.children.clone()
+    }
+}
+
+/// Recursively builds a sibling chain of `BookmarkNode`s starting at `first`,
+/// expanding each bookmark's children one level deeper.
+fn build_bookmark_siblings(
+    first: Option<pdfium_render::prelude::PdfBookmark>,
+    level: usize,
+) -> Vec<BookmarkNode> {
+    let mut nodes = Vec::new();
+    let mut current = first;
+    while let Some(bookmark) = current {
+        let title = bookmark.title().unwrap_or_default();
+        let page_idx = bookmark
+            .destination()
+            .and_then(|dest| dest.page_index().ok())
+            .map(|idx| idx as usize);
+        let children = build_bookmark_siblings(bookmark.first_child(), level + 1);
+        nodes.push(BookmarkNode {
+            title,
+            level,
+            page_idx,
+            children,
+        });
+        current = bookmark.next_sibling();
+    }
+    nodes
+}
+
+/// Resolves the logical page label for `page_idx` via Pdfium's own
+/// `FPDF_GetPageLabel`, which already implements the full `/PageLabels`
+/// number-tree lookup (decimal/roman/alphabetic styles, prefixes, and
+/// fallback to the decimal page number) per the PDF specification, so this
+/// crate doesn't need to re-walk the catalog's number tree by hand.
+fn resolve_page_label(pdfium: &Pdfium, doc: &PdfDocument<'static>, page_idx: usize) -> String {
+    let bindings = pdfium.bindings();
+    let handle = doc.handle();
+    let index = page_idx as std::os::raw::c_int;
+
+    let needed = bindings.FPDF_GetPageLabel(handle, index, std::ptr::null_mut(), 0);
+    if needed <= 2 {
+        return (page_idx + 1).to_string();
+    }
+
+    let mut buffer: Vec<u16> = vec![0u16; needed as usize / 2];
+    bindings.FPDF_GetPageLabel(
+        handle,
+        index,
+        buffer.as_mut_ptr() as *mut std::ffi::c_void,
+        needed,
+    );
+    let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..end])
+}
+
+/// The encryption permission flags of an opened `Document`.
+///
+/// These mirror the standard PDF permission bits; when a document has no
+/// owner password (or isn't encrypted at all) every flag is `true`.
+#[pyclass]
+#[derive(Debug, Clone, Copy)]
+pub struct DocumentPermissions {
+    /// Whether low-quality printing is permitted.
+    pub can_print: bool,
+    /// Whether high-quality (faithful) printing is permitted.
+    pub can_print_high_quality: bool,
+    /// Whether copying text/graphics out of the document is permitted.
+    pub can_copy: bool,
+    /// Whether modifying the document's contents is permitted.
+    pub can_modify: bool,
+    /// Whether adding or modifying text annotations is permitted.
+    pub can_annotate: bool,
+    /// Whether filling in existing form fields is permitted.
+    pub can_fill_forms: bool,
+    /// Whether inserting, deleting, or rotating pages is permitted.
+    pub can_assemble_document: bool,
+}
+
+#[pymethods]
+impl DocumentPermissions {
+    /// Whether low-quality printing is permitted.
+    #[getter]
+    fn can_print(&self) -> bool {
+        self.can_print
+    }
+
+    /// Whether high-quality (faithful) printing is permitted.
+    #[getter]
+    fn can_print_high_quality(&self) -> bool {
+        self.can_print_high_quality
+    }
+
+    /// Whether copying text/graphics out of the document is permitted.
+    #[getter]
+    fn can_copy(&self) -> bool {
+        self.can_copy
+    }
+
+    /// Whether modifying the document's contents is permitted.
+    #[getter]
+    fn can_modify(&self) -> bool {
+        self.can_modify
+    }
+
+    /// Whether adding or modifying text annotations is permitted.
+    #[getter]
+    fn can_annotate(&self) -> bool {
+        self.can_annotate
+    }
+
+    /// Whether filling in existing form fields is permitted.
+    #[getter]
+    fn can_fill_forms(&self) -> bool {
+        self.can_fill_forms
+    }
+
+    /// Whether inserting, deleting, or rotating pages is permitted.
+    #[getter]
+    fn can_assemble_document(&self) -> bool {
+        self.can_assemble_document
+    }
+}
+
 /// Iterator for traversing pages in a PDF document.
 ///
 /// This iterator is memory-efficient for large PDFs as it loads pages on demand.
@@ -393,6 +731,25 @@ impl PyPage {
         Ok(self.inner.page_idx)
     }
 
+    /// Returns the logical page label (e.g. `"iv"`, `"A-1"`) from the
+    /// document's `/PageLabels` number tree, falling back to the decimal
+    /// page number (`page_idx + 1`) when no label is defined.
+    #[getter]
+    fn label(&self) -> PyResult<String> {
+        self.check_valid()?;
+        let doc_inner = self.doc_inner.borrow();
+        if let Some(label) = doc_inner.page_label_cache.borrow().get(&self.inner.page_idx) {
+            return Ok(label.clone());
+        }
+        let doc = doc_inner.doc.as_ref().unwrap();
+        let label = resolve_page_label(&doc_inner.pdfium, doc, self.inner.page_idx);
+        doc_inner
+            .page_label_cache
+            .borrow_mut()
+            .insert(self.inner.page_idx, label.clone());
+        Ok(label)
+    }
+
     /// Returns the width of the page in points.
     #[getter]
     fn width(&self) -> PyResult<f32> {
@@ -446,12 +803,87 @@ impl PyPage {
         Ok(self.inner.objects.borrow().clone())
     }
 
+    /// Replaces the page's cached objects, e.g. with the output of
+    /// [`Objects.apply_transform`], so subsequent calls to [`get_edges`],
+    /// [`find_tables`], and the word/cluster pyfunctions operate on the
+    /// normalized geometry instead of the page's own raw extraction.
+    #[setter]
+    fn set_objects(&self, objects: Objects) -> PyResult<()> {
+        self.check_valid()?;
+        self.inner.objects.replace(Some(objects));
+        Ok(())
+    }
+
     /// Clears the cached objects to free memory.
     fn clear_cache(&self) -> PyResult<()> {
         self.check_valid()?;
         self.inner.clear();
         Ok(())
     }
+
+    /// Extracts this page's objects, then falls back to OCR on pages that
+    /// qualify under `mode` (see [`crate::ocr::page_needs_ocr`]), appending
+    /// recognized words as synthetic characters so word extraction and table
+    /// detection see them like native embedded text. Requires the `ocr`
+    /// Cargo feature. The engine used here is
+    /// [`crate::ocr::TesseractEngine`], which performs no recognition, so
+    /// no characters are appended.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - `"auto"` (OCR only pages with negligible embedded text),
+    ///   `"always"`, or `"never"`.
+    /// * `language` - The Tesseract language pack to use, e.g. `"eng"`.
+    /// * `dpi` - The DPI to rasterize the page at before running OCR.
+    #[cfg(feature = "ocr")]
+    #[pyo3(signature = (mode="auto", language="eng", dpi=300.0))]
+    fn extract_objects_with_ocr(&self, mode: &str, language: &str, dpi: f32) -> PyResult<()> {
+        self.check_valid()?;
+        let mode: crate::ocr::OcrMode = mode.parse()?;
+        let engine = crate::ocr::TesseractEngine::new(language);
+        self.inner.extract_objects_with_ocr(&engine, mode, dpi);
+        Ok(())
+    }
+
+    /// Rasterizes the page and draws the detected edges/cells/table
+    /// boundaries on top, for visually debugging table-detection settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The render scale factor (e.g. `2.0` for roughly 144 DPI).
+    /// * `tf_settings` - The table-finder settings to evaluate, or build one
+    ///   from `kwargs`.
+    /// * `draw_edges` / `draw_cells` / `draw_tables` - Which overlay layers
+    ///   to draw.
+    ///
+    /// # Returns
+    ///
+    /// A `(rgba_bytes, width, height)` tuple so callers can wrap the raw
+    /// buffer in PIL/numpy without this crate depending on either.
+    #[pyo3(signature = (scale=1.0, tf_settings=None, draw_edges=true, draw_cells=true, draw_tables=true, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    fn render_debug(
+        &self,
+        scale: f32,
+        tf_settings: Option<TfSettings>,
+        draw_edges: bool,
+        draw_cells: bool,
+        draw_tables: bool,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<(Vec<u8>, u32, u32)> {
+        self.check_valid()?;
+        let settings = Rc::new(match tf_settings {
+            Some(s) => s,
+            None => TfSettings::py_new(kwargs)?,
+        });
+        let layers = render_debug::DebugLayers {
+            edges: draw_edges,
+            cells: draw_cells,
+            tables: draw_tables,
+        };
+        render_debug::render_debug(&self.inner, settings, scale, layers)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))
+    }
 }
 
 /// Extracts edges (lines and rectangle borders) from a PDF page.
@@ -486,6 +918,434 @@ pub fn get_edges(page: &PyPage, settings: Option<&Bound<'_, PyDict>>) -> PyResul
     })
 }
 
+/// Returns the zero-based indices of pages in `path` whose dimensions fall
+/// within the given bounds, without loading any page in full.
+///
+/// Intended as an optional prefilter ahead of table extraction on large
+/// documents: call this first, then only [`Document.get_page`] the indices
+/// it returns, skipping e.g. tiny logo pages or oversized foldouts.
+///
+/// # Arguments
+///
+/// * `runtime` - The `PdfiumRuntime` to read page sizes through.
+/// * `path` - The file path to the PDF document.
+/// * `password` - Optional password for encrypted PDFs.
+/// * `min_width` / `max_width` / `min_height` / `max_height` - Inclusive
+///   bounds, in PDF points; a bound left as `None` is not enforced.
+///
+/// # Returns
+///
+/// The matching page indices, in document order.
+#[pyfunction]
+#[pyo3(
+    name = "prefilter_pages_by_size",
+    signature = (runtime, path, password=None, min_width=None, max_width=None, min_height=None, max_height=None)
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_prefilter_pages_by_size(
+    runtime: &PdfiumRuntime,
+    path: &str,
+    password: Option<&str>,
+    min_width: Option<f32>,
+    max_width: Option<f32>,
+    min_height: Option<f32>,
+    max_height: Option<f32>,
+) -> PyResult<Vec<usize>> {
+    let predicate = move |(width, height): page_size::PageDimensions| {
+        min_width.map_or(true, |min| width >= min)
+            && max_width.map_or(true, |max| width <= max)
+            && min_height.map_or(true, |min| height >= min)
+            && max_height.map_or(true, |max| height <= max)
+    };
+
+    page_size::prefilter_pages_by_size(&runtime.get_inner(), path, password, &predicate).map_err(
+        |e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to read page sizes: {:?}", e)),
+    )
+}
+
+/// Extracts `page`'s words with `settings`, ensuring its objects are
+/// populated first. Shared by the free pyfunctions that need a page's word
+/// layer (`search_text`, `text_density`, the clustering utilities) so each
+/// doesn't repeat the extract-then-borrow dance.
+fn extract_page_words(page: &PyPage, settings: &WordsExtractSettings) -> Vec<Word> {
+    let objects_opt = page.inner.objects.borrow();
+    if objects_opt.is_none() {
+        drop(objects_opt);
+        page.inner.extract_objects();
+    }
+    let objects_opt = page.inner.objects.borrow();
+    let objects = objects_opt.as_ref().expect("Objects should be extracted");
+    WordExtractor::new(settings).extract_words(&objects.chars)
+}
+
+/// A single match returned by [`py_search_text`].
+#[pyclass(name = "TextMatch")]
+#[derive(Debug, Clone)]
+pub struct TextMatch {
+    /// The matched word's text.
+    #[pyo3(get)]
+    pub text: String,
+    /// The matched word's bounding box.
+    pub bbox: BboxKey,
+    /// The edit distance between the query and the matched text (or, in
+    /// prefix mode, the best distance against any prefix of it).
+    #[pyo3(get)]
+    pub distance: usize,
+}
+
+#[pymethods]
+impl TextMatch {
+    /// The matched word's bounding box.
+    #[getter]
+    fn bbox(&self) -> PyBbox {
+        rs_bbox_to_py_bbox(&self.bbox)
+    }
+}
+
+/// Searches a page's extracted text layer for words within `max_distance`
+/// edits of `query`, tolerating the small typos that OCR or font-encoding
+/// quirks can introduce.
+///
+/// # Arguments
+///
+/// * `page` - The PDF page to search.
+/// * `query` - The term to search for.
+/// * `max_distance` - The maximum allowed edit distance (`0` for an exact,
+///   case/ligature-folded match).
+/// * `allow_transposition` - When `true`, swapping two adjacent characters
+///   counts as a single edit instead of two.
+/// * `prefix` - When `true`, a word matches if any prefix of it is within
+///   `max_distance` of `query`, rather than requiring the whole word to
+///   match.
+/// * `we_settings` / `kwargs` - Optional word extraction settings.
+///
+/// # Returns
+///
+/// Matching words with their bboxes and edit distances, in extraction order.
+#[pyfunction]
+#[pyo3(
+    name = "search_text",
+    signature = (page, query, max_distance=0, allow_transposition=false, prefix=false, we_settings=None, **kwargs)
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_search_text(
+    page: &PyPage,
+    query: &str,
+    max_distance: usize,
+    allow_transposition: bool,
+    prefix: bool,
+    we_settings: Option<WordsExtractSettings>,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Vec<TextMatch>> {
+    page.check_valid()?;
+    let settings = match we_settings {
+        Some(s) => s,
+        None => WordsExtractSettings::py_new(kwargs)?,
+    };
+    let words = extract_page_words(page, &settings);
+
+    Ok(
+        search::search_words(&words, query, max_distance, allow_transposition, prefix)
+            .into_iter()
+            .map(|(text, bbox, distance)| TextMatch { text, bbox, distance })
+            .collect(),
+    )
+}
+
+/// Counts how many characters or words have their bbox center in a grid
+/// bucket touched by `region` on `page`, using a [`crate::density::CharDensityIndex`] built
+/// over the whole page so repeated queries (e.g. probing several candidate
+/// gridlines) don't each re-scan every character.
+///
+/// # Arguments
+///
+/// * `page` - The PDF page to query.
+/// * `region` - The rectangle to count items within.
+/// * `kind` - Either `"char"` or `"word"`.
+/// * `bucket_size` - The side length, in PDF points, of the index's grid
+///   cells; smaller buckets give finer-grained counts at the cost of a
+///   larger index.
+///
+/// # Returns
+///
+/// The number of matching items whose bbox center falls in any grid bucket
+/// touched by `region`; counts are exact only to bucket granularity.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `kind` isn't `"char"` or `"word"`.
+#[pyfunction]
+#[pyo3(name = "text_density", signature = (page, region, kind="char", bucket_size=5.0))]
+fn py_text_density(
+    page: &PyPage,
+    region: PyBbox,
+    kind: &str,
+    bucket_size: f32,
+) -> PyResult<u32> {
+    page.check_valid()?;
+    let kind = match kind {
+        "char" => density::DensityKind::Char,
+        "word" => density::DensityKind::Word,
+        other => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "kind must be \"char\" or \"word\", got {other:?}"
+            )))
+        }
+    };
+
+    let objects_opt = page.inner.objects.borrow();
+    if objects_opt.is_none() {
+        drop(objects_opt);
+        page.inner.extract_objects();
+    }
+    let objects_opt = page.inner.objects.borrow();
+    let objects = objects_opt.as_ref().expect("Objects should be extracted");
+
+    let page_bbox = (
+        OrderedFloat(0.0),
+        OrderedFloat(0.0),
+        OrderedFloat(page.inner.width()),
+        OrderedFloat(page.inner.height()),
+    );
+    let words = WordExtractor::new(&WordsExtractSettings::default()).extract_words(&objects.chars);
+    let index = density::CharDensityIndex::new(page_bbox, &objects.chars, &words, bucket_size);
+    Ok(index.count_in_region(kind, py_bbox_to_rs_bbox(&region)))
+}
+
+/// Resolves `axis` to a key function over a word's bbox center, shared by
+/// the clustering pyfunctions that group words along a single dimension.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `axis` isn't `"x"` or `"y"`.
+fn word_axis_key(axis: &str) -> PyResult<fn(&Word) -> OrderedFloat<f32>> {
+    match axis {
+        "x" => Ok(|w: &Word| OrderedFloat((w.bbox.0.into_inner() + w.bbox.2.into_inner()) / 2.0)),
+        "y" => Ok(|w: &Word| OrderedFloat((w.bbox.1.into_inner() + w.bbox.3.into_inner()) / 2.0)),
+        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "axis must be \"x\" or \"y\", got {other:?}"
+        ))),
+    }
+}
+
+/// Parses a cluster mode string ("linkage" or "centroid") into a
+/// [`clusters::ClusterMode`].
+fn parse_cluster_mode(mode: &str) -> PyResult<clusters::ClusterMode> {
+    match mode {
+        "linkage" => Ok(clusters::ClusterMode::Linkage),
+        "centroid" => Ok(clusters::ClusterMode::Centroid),
+        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "mode must be \"linkage\" or \"centroid\", got {other:?}"
+        ))),
+    }
+}
+
+/// Converts clustered words into the plain `(text, bbox)` tuples returned by
+/// the clustering pyfunctions.
+fn words_to_py(words: Vec<Word>) -> Vec<(String, PyBbox)> {
+    words
+        .into_iter()
+        .map(|w| (w.text, rs_bbox_to_py_bbox(&w.bbox)))
+        .collect()
+}
+
+/// Groups a page's words into exactly `k` clusters along one axis via
+/// iterative k-means, for callers who know the expected number of
+/// columns/rows up front rather than a tolerance (see
+/// [`clusters::kmeans_cluster_objects`]).
+///
+/// # Arguments
+///
+/// * `page` - The PDF page to cluster words from.
+/// * `axis` - Either `"x"` (cluster by bbox horizontal center) or `"y"`
+///   (vertical center).
+/// * `k` - The number of clusters to produce.
+/// * `we_settings` / `kwargs` - Optional word extraction settings.
+///
+/// # Returns
+///
+/// Up to `k` clusters, each a list of `(text, bbox)` pairs, in ascending
+/// centroid order.
+#[pyfunction]
+#[pyo3(
+    name = "kmeans_cluster_words",
+    signature = (page, axis="x", k=2, we_settings=None, **kwargs)
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_kmeans_cluster_words(
+    page: &PyPage,
+    axis: &str,
+    k: usize,
+    we_settings: Option<WordsExtractSettings>,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Vec<Vec<(String, PyBbox)>>> {
+    page.check_valid()?;
+    let key_fn = word_axis_key(axis)?;
+    let settings = match we_settings {
+        Some(s) => s,
+        None => WordsExtractSettings::py_new(kwargs)?,
+    };
+    let words = extract_page_words(page, &settings);
+
+    Ok(clusters::kmeans_cluster_objects(&words, key_fn, k)
+        .into_iter()
+        .map(words_to_py)
+        .collect())
+}
+
+/// Groups a page's words jointly by (x, y) bbox-center position — i.e. by
+/// column and row simultaneously — rather than running two single-axis
+/// passes (see [`clusters::cluster_objects_by`]).
+///
+/// # Arguments
+///
+/// * `page` - The PDF page to cluster words from.
+/// * `tolerance` - The maximum distance, in PDF points, from a cluster's
+///   running centroid for a word to join it.
+/// * `we_settings` / `kwargs` - Optional word extraction settings.
+///
+/// # Returns
+///
+/// Clusters, each a list of `(text, bbox)` pairs, in the order they were
+/// first formed.
+#[pyfunction]
+#[pyo3(
+    name = "cluster_words_by_position",
+    signature = (page, tolerance, we_settings=None, **kwargs)
+)]
+fn py_cluster_words_by_position(
+    page: &PyPage,
+    tolerance: f32,
+    we_settings: Option<WordsExtractSettings>,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Vec<Vec<(String, PyBbox)>>> {
+    page.check_valid()?;
+    let settings = match we_settings {
+        Some(s) => s,
+        None => WordsExtractSettings::py_new(kwargs)?,
+    };
+    let words = extract_page_words(page, &settings);
+
+    let clustered = clusters::cluster_objects_by(
+        &words,
+        |w: &Word| clusters::Point2D {
+            x: (w.bbox.0.into_inner() + w.bbox.2.into_inner()) / 2.0,
+            y: (w.bbox.1.into_inner() + w.bbox.3.into_inner()) / 2.0,
+        },
+        tolerance,
+    );
+
+    Ok(clustered.into_iter().map(words_to_py).collect())
+}
+
+/// Clusters a page's words along one axis like [`py_kmeans_cluster_words`],
+/// then pulls out clusters smaller than `min_cluster_size` as a separate
+/// bucket of outliers (see [`clusters::cluster_objects_with_outliers`]).
+///
+/// # Arguments
+///
+/// * `page` - The PDF page to cluster words from.
+/// * `axis` - Either `"x"` or `"y"`.
+/// * `tolerance` - The maximum difference, in PDF points, for two words'
+///   axis keys to be in the same cluster.
+/// * `mode` - Whether membership within the base clustering pass is decided
+///   by single-linkage (`"linkage"`, the default) or by distance from a
+///   cluster's running centroid (`"centroid"`, which bounds how wide a
+///   single row/column can grow — see [`clusters::ClusterMode`]).
+/// * `min_cluster_size` - Clusters smaller than this are treated as outliers.
+/// * `outlier_tolerance_multiplier` - How many multiples of `tolerance` a
+///   small cluster's word may be from the nearest large cluster's centroid
+///   and still be folded into it rather than reported as an outlier.
+/// * `we_settings` / `kwargs` - Optional word extraction settings.
+///
+/// # Returns
+///
+/// A `(clusters, outliers)` pair: the retained clusters (each a list of
+/// `(text, bbox)` pairs) and the outlier words.
+#[pyfunction]
+#[pyo3(
+    name = "cluster_words_with_outliers",
+    signature = (page, axis="x", tolerance=3.0, mode="linkage", min_cluster_size=2, outlier_tolerance_multiplier=3.0, we_settings=None, **kwargs)
+)]
+#[allow(clippy::too_many_arguments)]
+fn py_cluster_words_with_outliers(
+    page: &PyPage,
+    axis: &str,
+    tolerance: f32,
+    mode: &str,
+    min_cluster_size: usize,
+    outlier_tolerance_multiplier: f32,
+    we_settings: Option<WordsExtractSettings>,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<(Vec<Vec<(String, PyBbox)>>, Vec<(String, PyBbox)>)> {
+    page.check_valid()?;
+    let key_fn = word_axis_key(axis)?;
+    let mode = parse_cluster_mode(mode)?;
+    let settings = match we_settings {
+        Some(s) => s,
+        None => WordsExtractSettings::py_new(kwargs)?,
+    };
+    let words = extract_page_words(page, &settings);
+
+    let result = clusters::cluster_objects_with_outliers(
+        &words,
+        key_fn,
+        OrderedFloat(tolerance),
+        mode,
+        min_cluster_size,
+        outlier_tolerance_multiplier,
+    );
+
+    Ok((
+        result.clusters.into_iter().map(words_to_py).collect(),
+        words_to_py(result.outliers),
+    ))
+}
+
+/// Clusters a page's words along one axis by running
+/// [`clusters::consensus_cluster_objects`] over several candidate
+/// tolerances and taking a majority vote, instead of committing to one
+/// hand-tuned tolerance.
+///
+/// # Arguments
+///
+/// * `page` - The PDF page to cluster words from.
+/// * `tolerances` - The candidate tolerances (in PDF points) to vote across.
+/// * `axis` - Either `"x"` or `"y"`.
+/// * `we_settings` / `kwargs` - Optional word extraction settings.
+///
+/// # Returns
+///
+/// Clusters, each a list of `(text, bbox)` pairs, in ascending key order.
+#[pyfunction]
+#[pyo3(
+    name = "consensus_cluster_words",
+    signature = (page, tolerances, axis="x", we_settings=None, **kwargs)
+)]
+fn py_consensus_cluster_words(
+    page: &PyPage,
+    tolerances: Vec<f32>,
+    axis: &str,
+    we_settings: Option<WordsExtractSettings>,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Vec<Vec<(String, PyBbox)>>> {
+    page.check_valid()?;
+    let key_fn = word_axis_key(axis)?;
+    let settings = match we_settings {
+        Some(s) => s,
+        None => WordsExtractSettings::py_new(kwargs)?,
+    };
+    let words = extract_page_words(page, &settings);
+    let tolerances: Vec<OrderedFloat<f32>> = tolerances.into_iter().map(OrderedFloat).collect();
+
+    Ok(
+        clusters::consensus_cluster_objects(&words, key_fn, &tolerances)
+            .into_iter()
+            .map(words_to_py)
+            .collect(),
+    )
+}
+
 /// Converts a Rust bounding box to a Python tuple.
 ///
 /// # Arguments
@@ -521,6 +1381,69 @@ fn py_bbox_to_rs_bbox(bbox: &PyBbox) -> BboxKey {
         OrderedFloat(bbox.3),
     )
 }
+
+/// Returns the rectangle where `a` and `b` overlap, or `None` if they don't.
+///
+/// # Arguments
+///
+/// * `a` - The first bounding box (x1, y1, x2, y2).
+/// * `b` - The second bounding box (x1, y1, x2, y2).
+///
+/// # Returns
+///
+/// The overlapping rectangle, or `None` if `a` and `b` don't overlap.
+#[pyfunction]
+fn bbox_intersection(a: PyBbox, b: PyBbox) -> Option<PyBbox> {
+    objects::bbox_intersection(py_bbox_to_rs_bbox(&a), py_bbox_to_rs_bbox(&b))
+        .map(|bbox| rs_bbox_to_py_bbox(&bbox))
+}
+
+/// Returns the area of `bbox`, or `0.0` if it is degenerate (zero or
+/// negative width/height).
+///
+/// # Arguments
+///
+/// * `bbox` - The bounding box (x1, y1, x2, y2).
+///
+/// # Returns
+///
+/// The area of `bbox`.
+#[pyfunction]
+fn bbox_area(bbox: PyBbox) -> f32 {
+    objects::bbox_area(py_bbox_to_rs_bbox(&bbox))
+}
+
+/// Returns `true` if `a` fully contains `b` (i.e. `a & b == b`).
+///
+/// # Arguments
+///
+/// * `a` - The (potentially) containing bounding box.
+/// * `b` - The (potentially) contained bounding box.
+///
+/// # Returns
+///
+/// `true` if `a` fully contains `b`.
+#[pyfunction]
+fn bbox_contains(a: PyBbox, b: PyBbox) -> bool {
+    objects::bbox_contains(py_bbox_to_rs_bbox(&a), py_bbox_to_rs_bbox(&b))
+}
+
+/// Returns the fraction of `b`'s own area that falls inside `a`, or `0.0`
+/// if they don't overlap (or `b` has zero area).
+///
+/// # Arguments
+///
+/// * `a` - The query bounding box (x1, y1, x2, y2).
+/// * `b` - The bounding box whose overlap fraction is computed.
+///
+/// # Returns
+///
+/// The fraction of `b`'s area that falls inside `a`.
+#[pyfunction]
+fn bbox_intersection_over(a: PyBbox, b: PyBbox) -> f32 {
+    objects::bbox_intersection_over(py_bbox_to_rs_bbox(&a), py_bbox_to_rs_bbox(&b))
+}
+
 /// Finds all table cell bounding boxes in a PDF page.
 ///
 /// # Arguments
@@ -633,6 +1556,65 @@ fn py_find_tables(
     Ok(tables)
 }
 
+/// Concatenates the rendered rows of several tables into one grid, in
+/// order, padding short rows so every row has the widest column count seen
+/// across all the tables.
+///
+/// # Arguments
+///
+/// * `tables` - The tables to merge, in the order their rows should appear.
+///
+/// # Returns
+///
+/// The merged grid as a list of rows, each a list of optional cell text.
+#[pyfunction]
+#[pyo3(name = "merge_tables")]
+fn py_merge_tables(tables: &Bound<'_, PyList>) -> PyResult<Vec<Vec<Option<String>>>> {
+    let refs: Vec<PyRef<'_, Table>> = tables
+        .iter()
+        .map(|item| item.extract::<PyRef<Table>>())
+        .collect::<PyResult<_>>()?;
+    Ok(merge::merge_tables(refs.iter().map(|r| &**r)))
+}
+
+/// Parallel equivalent of [`py_merge_tables`]: see
+/// [`merge::merge_tables_par`] for how it spreads `to_grid` rendering
+/// across worker threads.
+#[pyfunction]
+#[pyo3(name = "merge_tables_par")]
+fn py_merge_tables_par(tables: &Bound<'_, PyList>) -> PyResult<Vec<Vec<Option<String>>>> {
+    let refs: Vec<PyRef<'_, Table>> = tables
+        .iter()
+        .map(|item| item.extract::<PyRef<Table>>())
+        .collect::<PyResult<_>>()?;
+    Ok(merge::merge_tables_par(refs.iter().map(|r| &**r)))
+}
+
+/// Performs an equality join between two tables' columns, using a
+/// `StringIndex` built over `right` so each `left` row only visits the
+/// `right` rows it actually matches instead of a full cross-scan.
+///
+/// # Arguments
+///
+/// * `left` - The left table.
+/// * `left_col` - The column index to join on in `left`.
+/// * `right` - The right table.
+/// * `right_col` - The column index to join on in `right`.
+///
+/// # Returns
+///
+/// `(left_row, right_row)` pairs for every matching value.
+#[pyfunction]
+#[pyo3(name = "equi_join_tables")]
+fn py_equi_join_tables(
+    left: PyRef<'_, Table>,
+    left_col: usize,
+    right: PyRef<'_, Table>,
+    right_col: usize,
+) -> Vec<(usize, usize)> {
+    index::equi_join_on_index(&left, left_col, &right, right_col)
+}
+
 /// Initializes the tablers Python module.
 ///
 /// This function is called by Python when importing the module and registers
@@ -642,17 +1624,37 @@ fn tablers(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     m.add_class::<PdfiumRuntime>()?;
     m.add_class::<Document>()?;
+    m.add_class::<BookmarkNode>()?;
+    m.add_class::<DocumentPermissions>()?;
     m.add_class::<PyPage>()?;
     m.add_class::<PyPageIterator>()?;
     m.add_class::<Edge>()?;
+    m.add_class::<Matrix>()?;
     m.add_class::<TableCell>()?;
     m.add_class::<Table>()?;
+    m.add_class::<ColumnStats>()?;
+    m.add_class::<StringIndex>()?;
     m.add_class::<TfSettings>()?;
     m.add_class::<WordsExtractSettings>()?;
+    m.add_class::<TextMatch>()?;
     m.add_function(pyo3::wrap_pyfunction!(py_find_all_cells_bboxes, m)?)?;
     m.add_function(pyo3::wrap_pyfunction!(py_find_tables_from_cells, m)?)?;
     m.add_function(pyo3::wrap_pyfunction!(py_find_tables, m)?)?;
     m.add_function(pyo3::wrap_pyfunction!(get_edges, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_prefilter_pages_by_size, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(bbox_intersection, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(bbox_area, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(bbox_contains, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(bbox_intersection_over, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_merge_tables, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_merge_tables_par, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_equi_join_tables, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_search_text, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_text_density, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_kmeans_cluster_words, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_cluster_words_by_position, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_cluster_words_with_outliers, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(py_consensus_cluster_words, m)?)?;
     Ok(())
 }
 
@@ -0,0 +1,270 @@
+use crate::tables::Table;
+
+/// Concatenates the rendered row grids of `tables`, in order, into one grid.
+///
+/// Each table is rendered via [`Table::to_grid`] with spanned cells
+/// collapsed to their top-left position (matching the grid `to_csv`/
+/// `to_markdown` build on). Rows shorter than the widest row seen across
+/// all tables are padded on the right with `None` so every output row has
+/// the same length.
+pub fn merge_tables<'a>(tables: impl IntoIterator<Item = &'a Table>) -> Vec<Vec<Option<String>>> {
+    let grids: Vec<Vec<Vec<Option<String>>>> =
+        tables.into_iter().map(|t| t.to_grid(false)).collect();
+    pad_and_concat(grids)
+}
+
+/// Applies `f` to every row of `grid`, returning the transformed grid in
+/// the same row order.
+pub fn map_rows(
+    grid: &[Vec<Option<String>>],
+    f: impl Fn(&[Option<String>]) -> Vec<Option<String>>,
+) -> Vec<Vec<Option<String>>> {
+    grid.iter().map(|row| f(row)).collect()
+}
+
+/// Pads every row across `grids` to the widest row seen anywhere, then
+/// concatenates the grids in order.
+fn pad_and_concat(grids: Vec<Vec<Vec<Option<String>>>>) -> Vec<Vec<Option<String>>> {
+    let width = grids
+        .iter()
+        .flat_map(|grid| grid.iter().map(Vec::len))
+        .max()
+        .unwrap_or(0);
+
+    grids
+        .into_iter()
+        .flat_map(|grid| {
+            grid.into_iter().map(move |mut row| {
+                row.resize(width, None);
+                row
+            })
+        })
+        .collect()
+}
+
+/// Parallel equivalent of [`merge_tables`]: renders each table's grid (the
+/// expensive part, since [`Table::to_grid`] walks every cell) across a pool
+/// of `std::thread::scope` worker threads, then pads and concatenates the
+/// results serially, in the same order as [`merge_tables`].
+pub fn merge_tables_par<'a>(
+    tables: impl IntoIterator<Item = &'a Table>,
+) -> Vec<Vec<Option<String>>> {
+    let tables: Vec<&Table> = tables.into_iter().collect();
+    let grids = parallel_map(&tables, |t| t.to_grid(false));
+    pad_and_concat(grids)
+}
+
+/// Parallel equivalent of [`map_rows`]: applies `f` to every row of `grid`
+/// across a pool of `std::thread::scope` worker threads, returning the
+/// transformed grid in the same row order.
+pub fn map_rows_par(
+    grid: &[Vec<Option<String>>],
+    f: impl Fn(&[Option<String>]) -> Vec<Option<String>> + Sync,
+) -> Vec<Vec<Option<String>>> {
+    parallel_map(grid, |row| f(row))
+}
+
+/// Applies `f` to every item in `items` across `std::thread::available_
+/// parallelism()` worker threads (falling back to a plain serial map when
+/// there's only one item, or the platform can't report a thread count),
+/// returning results in the same order as `items`.
+fn parallel_map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let thread_count = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(items.len().max(1));
+
+    if thread_count <= 1 {
+        return items.iter().map(&f).collect();
+    }
+
+    let chunk_size = (items.len() + thread_count - 1) / thread_count;
+    let mut results: Vec<Option<R>> = (0..items.len()).map(|_| None).collect();
+
+    std::thread::scope(|scope| {
+        for (out_chunk, in_chunk) in results.chunks_mut(chunk_size).zip(items.chunks(chunk_size)) {
+            let f = &f;
+            scope.spawn(move || {
+                for (out, item) in out_chunk.iter_mut().zip(in_chunk) {
+                    *out = Some(f(item));
+                }
+            });
+        }
+    });
+
+    results.into_iter().map(|r| r.unwrap()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ordered_float::OrderedFloat;
+    use crate::tables::TableCell;
+
+    fn make_test_table(page_index: usize, text: &str) -> Table {
+        Table {
+            cells: vec![TableCell {
+                text: text.to_string(),
+                bbox: (
+                    OrderedFloat(0.0),
+                    OrderedFloat(0.0),
+                    OrderedFloat(10.0),
+                    OrderedFloat(5.0),
+                ),
+                col_start: 0,
+                colspan: 1,
+                row_start: 0,
+                rowspan: 1,
+            }],
+            bbox: (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(5.0),
+            ),
+            page_index,
+            text_extracted: true,
+        }
+    }
+
+    #[test]
+    fn test_merge_tables_concatenates_rows_in_order() {
+        let a = make_test_table(0, "a");
+        let b = make_test_table(1, "b");
+
+        let merged = merge_tables([&a, &b]);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0][0].as_deref(), Some("a"));
+        assert_eq!(merged[1][0].as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_merge_tables_pads_short_rows_to_widest_column_count() {
+        let narrow = Table {
+            cells: vec![TableCell {
+                text: "x".to_string(),
+                bbox: (
+                    OrderedFloat(0.0),
+                    OrderedFloat(0.0),
+                    OrderedFloat(10.0),
+                    OrderedFloat(5.0),
+                ),
+                col_start: 0,
+                colspan: 1,
+                row_start: 0,
+                rowspan: 1,
+            }],
+            bbox: (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(5.0),
+            ),
+            page_index: 0,
+            text_extracted: true,
+        };
+        let wide = Table {
+            cells: vec![
+                TableCell {
+                    text: "y".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "z".to_string(),
+                    bbox: (
+                        OrderedFloat(10.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(20.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 1,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 1,
+                },
+            ],
+            bbox: (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(20.0),
+                OrderedFloat(5.0),
+            ),
+            page_index: 0,
+            text_extracted: true,
+        };
+
+        let merged = merge_tables([&narrow, &wide]);
+
+        assert_eq!(merged[0].len(), 2);
+        assert_eq!(merged[0][0].as_deref(), Some("x"));
+        assert_eq!(merged[0][1], None);
+        assert_eq!(merged[1][1].as_deref(), Some("z"));
+    }
+
+    #[test]
+    fn test_map_rows_applies_transform_to_every_row() {
+        let grid = vec![
+            vec![Some("a".to_string())],
+            vec![Some("b".to_string())],
+        ];
+
+        let upper = map_rows(&grid, |row| {
+            row.iter()
+                .map(|cell| cell.as_deref().map(str::to_uppercase))
+                .collect()
+        });
+
+        assert_eq!(upper[0][0].as_deref(), Some("A"));
+        assert_eq!(upper[1][0].as_deref(), Some("B"));
+    }
+
+    #[test]
+    fn test_merge_tables_par_matches_serial_output() {
+        let tables: Vec<Table> = (0..20).map(|i| make_test_table(i, &i.to_string())).collect();
+
+        let serial = merge_tables(&tables);
+        let parallel = merge_tables_par(&tables);
+
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn test_map_rows_par_matches_serial_output_and_order() {
+        let grid: Vec<Vec<Option<String>>> = (0..20)
+            .map(|i| vec![Some(i.to_string())])
+            .collect();
+
+        let upper = |row: &[Option<String>]| -> Vec<Option<String>> {
+            row.iter()
+                .map(|cell| cell.as_deref().map(str::to_uppercase))
+                .collect()
+        };
+
+        let serial = map_rows(&grid, upper);
+        let parallel = map_rows_par(&grid, upper);
+
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn test_parallel_map_preserves_item_order() {
+        let items: Vec<i32> = (0..50).collect();
+        let doubled = parallel_map(&items, |x| x * 2);
+
+        assert_eq!(doubled, items.iter().map(|x| x * 2).collect::<Vec<_>>());
+    }
+}
@@ -1,6 +1,7 @@
 use ordered_float::OrderedFloat;
 use pdfium_render::prelude::PdfColor;
 use pyo3::prelude::*;
+use std::cmp;
 
 /// Container for all extracted objects from a PDF page.
 ///
@@ -19,6 +20,220 @@ pub struct Objects {
     pub chars: Vec<Char>,
 }
 
+#[pymethods]
+impl Objects {
+    /// Returns the characters whose bbox overlaps `rect` by at least
+    /// `overlap_ratio` of the character's own area.
+    ///
+    /// This is the inverse of [`get_objects_bbox`]: instead of computing the
+    /// bbox that encloses a set of objects, it finds the objects that fall
+    /// inside a given bbox — the core operation when mapping extracted text
+    /// onto detected table cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The query region as `(x1, y1, x2, y2)`.
+    /// * `overlap_ratio` - The minimum fraction of a character's own bbox
+    ///   area that must fall inside `rect` for it to be included.
+    ///
+    /// # Returns
+    ///
+    /// The matching characters, in their original extraction order.
+    fn chars_in_rect(&self, rect: (f32, f32, f32, f32), overlap_ratio: f32) -> Vec<Char> {
+        let query = (
+            OrderedFloat(rect.0),
+            OrderedFloat(rect.1),
+            OrderedFloat(rect.2),
+            OrderedFloat(rect.3),
+        );
+        objects_in_rect(&self.chars, query, overlap_ratio)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Maps every extracted object through an affine transform, in place.
+    ///
+    /// Normalizes a page's coordinate system — e.g. flipping PDF's
+    /// bottom-up y-axis, undoing a page rotation, or rescaling to a target
+    /// DPI — before table detection, so geometry is comparable across
+    /// documents regardless of how each one was produced.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The affine transform to apply.
+    fn apply_transform(&mut self, matrix: Matrix) {
+        for rect in &mut self.rects {
+            rect.bbox = transform_bbox(&matrix, rect.bbox);
+        }
+        for line in &mut self.lines {
+            for point in &mut line.points {
+                *point = matrix.apply_point(*point);
+            }
+        }
+        for char in &mut self.chars {
+            char.bbox = transform_bbox(&matrix, char.bbox);
+        }
+    }
+}
+
+/// An affine transform `(a, b, c, d, e, f)`, mapping `(x, y)` to
+/// `(a*x + c*y + e, b*x + d*y + f)` — the same convention as a PDF content
+/// stream's `cm` operator and [`Char::text_matrix`].
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix {
+    #[pyo3(get)]
+    pub a: f32,
+    #[pyo3(get)]
+    pub b: f32,
+    #[pyo3(get)]
+    pub c: f32,
+    #[pyo3(get)]
+    pub d: f32,
+    #[pyo3(get)]
+    pub e: f32,
+    #[pyo3(get)]
+    pub f: f32,
+}
+
+#[pymethods]
+impl Matrix {
+    /// The identity transform, mapping every point to itself.
+    #[staticmethod]
+    pub fn identity() -> Self {
+        Matrix {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// A pure translation by `(tx, ty)`.
+    #[staticmethod]
+    pub fn translate(tx: f32, ty: f32) -> Self {
+        Matrix {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: tx,
+            f: ty,
+        }
+    }
+
+    /// A pure scale by `(sx, sy)` about the origin.
+    #[staticmethod]
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Matrix {
+            a: sx,
+            b: 0.0,
+            c: 0.0,
+            d: sy,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// A uniform scale that rescales coordinates from `from_dpi` to
+    /// `to_dpi`.
+    #[staticmethod]
+    pub fn scale_to_dpi(from_dpi: f32, to_dpi: f32) -> Self {
+        Self::scale(to_dpi / from_dpi, to_dpi / from_dpi)
+    }
+
+    /// Flips the y-axis about the horizontal line `y = height / 2`,
+    /// turning PDF's bottom-up origin into a top-left one (or back).
+    #[staticmethod]
+    pub fn flip_y(height: f32) -> Self {
+        Matrix {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: -1.0,
+            e: 0.0,
+            f: height,
+        }
+    }
+
+    /// A rotation about the origin by `quarter_turns * 90` degrees
+    /// clockwise. Only multiples of 90° are supported so the result stays
+    /// exact (no sine/cosine rounding) and axis-aligned bboxes stay
+    /// axis-aligned after the transform.
+    #[staticmethod]
+    pub fn rotate90(quarter_turns: i32) -> Self {
+        let (a, b, c, d) = match quarter_turns.rem_euclid(4) {
+            0 => (1.0, 0.0, 0.0, 1.0),
+            1 => (0.0, 1.0, -1.0, 0.0),
+            2 => (-1.0, 0.0, 0.0, -1.0),
+            _ => (0.0, -1.0, 1.0, 0.0),
+        };
+        Matrix {
+            a,
+            b,
+            c,
+            d,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// Composes this transform with `next`, so that applying the result is
+    /// equivalent to applying `self` first and `next` second.
+    pub fn then(&self, next: &Matrix) -> Matrix {
+        Matrix {
+            a: self.a * next.a + self.b * next.c,
+            b: self.a * next.b + self.b * next.d,
+            c: self.c * next.a + self.d * next.c,
+            d: self.c * next.b + self.d * next.d,
+            e: self.e * next.a + self.f * next.c + next.e,
+            f: self.e * next.b + self.f * next.d + next.f,
+        }
+    }
+
+    /// Applies this transform to a single point.
+    pub fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+}
+
+impl Matrix {
+    /// Applies this transform to a [`Point`] (not exposed to Python, which
+    /// uses plain `(f32, f32)` tuples via [`Matrix::apply`]).
+    fn apply_point(&self, point: Point) -> Point {
+        let (x, y) = self.apply(point.0.into_inner(), point.1.into_inner());
+        (OrderedFloat(x), OrderedFloat(y))
+    }
+}
+
+/// Recomputes an axis-aligned bbox after an affine transform.
+///
+/// Maps the bbox's four corners through `matrix` and takes their
+/// enclosing box, rather than naively transforming just the two stored
+/// corners — which would give the wrong box under any rotation. The
+/// transformed quad is also classified with [`rect_shape`], the
+/// rotation-aware rectangle check, as a sanity check that `matrix` kept
+/// the bbox a rectangle (true for every constructor [`Matrix`] exposes).
+fn transform_bbox(matrix: &Matrix, bbox: BboxKey) -> BboxKey {
+    let (x1, y1, x2, y2) = bbox;
+    let corners = [(x1, y1), (x2, y1), (x2, y2), (x1, y2), (x1, y1)];
+    let transformed: Vec<Point> = corners
+        .into_iter()
+        .map(|point| matrix.apply_point(point))
+        .collect();
+    debug_assert!(
+        rect_shape(&transformed).is_some(),
+        "affine transform turned an axis-aligned bbox into a non-rectangle"
+    );
+    merge_bboxes(transformed[..4].iter().map(|&(x, y)| (x, y, x, y))).unwrap()
+}
+
 /// A 2D point represented as (x, y) coordinates.
 pub type Point = (OrderedFloat<f32>, OrderedFloat<f32>);
 
@@ -141,9 +356,142 @@ impl Line {
     }
 }
 
+/// Maximum de Casteljau subdivision depth for [`Line::flatten`], a
+/// backstop against pathological inputs that never satisfy `tolerance`.
+const MAX_FLATTEN_DEPTH: u32 = 24;
+
+/// Two points closer than this, in either coordinate, are treated as
+/// coincident when deduplicating consecutive flattened points.
+const FLATTEN_POINT_EPSILON: f32 = 1e-6;
+
+impl Line {
+    /// Flattens a `LineType::Curve` into a `LineType::Straight` polyline
+    /// within `tolerance` of the original curve.
+    ///
+    /// `points` is read as a start anchor followed by one or more
+    /// `(control1, control2, end)` triples, each cubic Bezier segment
+    /// chained from the previous segment's end — the layout curved path
+    /// objects are built with. Each segment is recursively subdivided
+    /// (de Casteljau) until the maximum perpendicular distance of its two
+    /// control points from the chord between its endpoints is within
+    /// `tolerance`, at which point the chord is emitted as a straight
+    /// segment. Degenerate segments (all four points nearly coincident)
+    /// collapse to a single point, and consecutive duplicate points are
+    /// never emitted.
+    ///
+    /// Already-straight lines are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The maximum allowed deviation from the true curve,
+    ///   in the same units as the line's points.
+    ///
+    /// # Returns
+    ///
+    /// A new `Line` with `line_type` set to `LineType::Straight`.
+    pub fn flatten(&self, tolerance: f32) -> Line {
+        if self.line_type == LineType::Straight || self.points.len() < 4 {
+            return self.clone();
+        }
+
+        let mut flattened = Vec::with_capacity(self.points.len());
+        flattened.push(self.points[0]);
+
+        let mut start = self.points[0];
+        for segment in self.points[1..].chunks(3) {
+            let [control1, control2, end] = segment else {
+                break;
+            };
+            flatten_cubic(start, *control1, *control2, *end, tolerance, 0, &mut flattened);
+            start = *end;
+        }
+
+        Line {
+            line_type: LineType::Straight,
+            points: flattened,
+            color: self.color.clone(),
+            width: self.width,
+        }
+    }
+}
+
+/// Recursively subdivides the cubic Bezier segment `p0..p3` (de Casteljau)
+/// until it is within `tolerance` of its chord, appending the resulting
+/// straight-segment endpoints to `out`. `p0` itself is not appended; the
+/// caller is expected to have already seeded `out` with it.
+fn flatten_cubic(
+    p0: Point,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    tolerance: f32,
+    depth: u32,
+    out: &mut Vec<Point>,
+) {
+    if depth >= MAX_FLATTEN_DEPTH || is_flat_enough(p0, p1, p2, p3, tolerance) {
+        push_if_distinct(out, p3);
+        return;
+    }
+
+    // Standard de Casteljau split at t=0.5: midpoints of each leg, then
+    // midpoints of those, yielding the two sub-curves' control points.
+    let p01 = midpoint(p0, p1);
+    let p12 = midpoint(p1, p2);
+    let p23 = midpoint(p2, p3);
+    let p012 = midpoint(p01, p12);
+    let p123 = midpoint(p12, p23);
+    let p0123 = midpoint(p012, p123);
+
+    flatten_cubic(p0, p01, p012, p0123, tolerance, depth + 1, out);
+    flatten_cubic(p0123, p123, p23, p3, tolerance, depth + 1, out);
+}
+
+/// Returns `true` if both control points `p1` and `p2` lie within
+/// `tolerance` of the chord from `p0` to `p3`.
+fn is_flat_enough(p0: Point, p1: Point, p2: Point, p3: Point, tolerance: f32) -> bool {
+    perpendicular_distance(p1, p0, p3) <= tolerance
+        && perpendicular_distance(p2, p0, p3) <= tolerance
+}
+
+/// Returns the perpendicular distance of `p` from the line through `a` and
+/// `b`, or the distance from `p` to `a` if `a` and `b` nearly coincide (no
+/// well-defined line direction).
+fn perpendicular_distance(p: Point, a: Point, b: Point) -> f32 {
+    let (px, py) = (p.0.into_inner(), p.1.into_inner());
+    let (ax, ay) = (a.0.into_inner(), a.1.into_inner());
+    let (bx, by) = (b.0.into_inner(), b.1.into_inner());
+
+    let dx = bx - ax;
+    let dy = by - ay;
+    let chord_len = (dx * dx + dy * dy).sqrt();
+    if chord_len < f32::EPSILON {
+        return ((px - ax).powi(2) + (py - ay).powi(2)).sqrt();
+    }
+
+    ((px - ax) * dy - (py - ay) * dx).abs() / chord_len
+}
+
+/// Returns the midpoint of `a` and `b`.
+fn midpoint(a: Point, b: Point) -> Point {
+    ((a.0 + b.0) / OrderedFloat(2.0), (a.1 + b.1) / OrderedFloat(2.0))
+}
+
+/// Appends `p` to `out` unless it is within [`FLATTEN_POINT_EPSILON`] of
+/// the last point already in `out`.
+fn push_if_distinct(out: &mut Vec<Point>, p: Point) {
+    let is_duplicate = out.last().is_some_and(|&last| {
+        (last.0 - p.0).abs() < OrderedFloat(FLATTEN_POINT_EPSILON)
+            && (last.1 - p.1).abs() < OrderedFloat(FLATTEN_POINT_EPSILON)
+    });
+    if !is_duplicate {
+        out.push(p);
+    }
+}
+
 /// Represents a text character extracted from a PDF page.
 ///
-/// Each character includes its Unicode value, position, and rotation information.
+/// Each character includes its Unicode value, position, rotation, and font
+/// information.
 #[pyclass]
 #[derive(Debug, Clone)]
 pub struct Char {
@@ -157,6 +505,15 @@ pub struct Char {
     /// Whether the character is upright (horizontal text).
     #[pyo3(get)]
     pub upright: bool,
+    /// The font size, in points.
+    pub font_size: OrderedFloat<f32>,
+    /// The PostScript name of the character's font, if available.
+    #[pyo3(get)]
+    pub font_name: Option<String>,
+    /// The fill color used to paint the character.
+    pub fill_color: PdfColor,
+    /// The character's text matrix as `(a, b, c, d, e, f)`.
+    pub text_matrix: (f32, f32, f32, f32, f32, f32),
 }
 #[pymethods]
 impl Char {
@@ -176,6 +533,29 @@ impl Char {
     fn rotation_degrees(&self) -> f32 {
         self.rotation_degrees.into_inner()
     }
+
+    /// Returns the font size, in points.
+    #[getter]
+    fn font_size(&self) -> f32 {
+        self.font_size.into_inner()
+    }
+
+    /// Returns the fill color as an RGBA tuple.
+    #[getter]
+    fn fill_color(&self) -> (u8, u8, u8, u8) {
+        (
+            self.fill_color.red(),
+            self.fill_color.green(),
+            self.fill_color.blue(),
+            self.fill_color.alpha(),
+        )
+    }
+
+    /// Returns the text matrix as a tuple `(a, b, c, d, e, f)`.
+    #[getter]
+    fn text_matrix(&self) -> (f32, f32, f32, f32, f32, f32) {
+        self.text_matrix
+    }
 }
 
 impl HasBbox for Char {
@@ -202,37 +582,135 @@ pub enum LineType {
     Curve,
 }
 
-/// Checks if a set of points forms a rectangle.
+/// Relative tolerance used when classifying a quad as a rectangle: adjacent
+/// edges are accepted as perpendicular when their length-normalized dot
+/// product is within this of zero, and opposite edges as equal-length when
+/// they differ by less than this fraction of their length.
+const RECT_TOLERANCE: f32 = 1e-3;
+
+/// The geometry of a quad recognized as a rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RectShape {
+    /// Rotation of the first edge, in radians, as `atan2(dy, dx)`.
+    pub rotation: f32,
+    /// Length of the first (and third) edge.
+    pub width: f32,
+    /// Length of the second (and fourth) edge.
+    pub height: f32,
+}
+
+/// Classifies a closed polyline of 5 points (4 corners + closing point) as
+/// a rectangle, returning its rotation and extent.
 ///
-/// A valid rectangle has 5 points (4 corners + closing point) where
-/// the first and last points are the same.
+/// A valid rectangle has 5 points where the first and last are the same.
+/// The four edge vectors are formed and the quad is accepted as a
+/// rectangle when each pair of adjacent edges is perpendicular (dot
+/// product within [`RECT_TOLERANCE`] of zero once normalized by edge
+/// length) and opposite edges have equal length within the same
+/// tolerance. This accepts rectangles rotated at an arbitrary angle, and
+/// tolerates the floating-point noise pdfium's curve flattening can leave
+/// on otherwise-rectangular quads. An axis-aligned quad is detected with a
+/// fast exact-equality path first, since that is the overwhelming majority
+/// of cases.
 ///
 /// # Arguments
 ///
-/// * `points` - A slice of points to check.
+/// * `points` - The closed polyline to classify.
 ///
 /// # Returns
 ///
-/// `true` if the points form a rectangle, `false` otherwise.
-pub(crate) fn is_rect(points: &[Point]) -> bool {
+/// The rectangle's [`RectShape`], or `None` if `points` isn't a rectangle
+/// (this includes quads with a zero-length edge).
+pub(crate) fn rect_shape(points: &[Point]) -> Option<RectShape> {
     if points.len() != 5 || points[0] != points[4] {
-        return false;
+        return None;
     }
+
     if points[0].0 == points[1].0
         && points[1].1 == points[2].1
         && points[2].0 == points[3].0
         && points[3].1 == points[0].1
     {
-        return true;
+        let dx = (points[1].0 - points[0].0).into_inner();
+        let dy = (points[1].1 - points[0].1).into_inner();
+        let width = dy.abs();
+        let height = (points[2].0 - points[1].0).into_inner().abs();
+        if width == 0.0 || height == 0.0 {
+            return None;
+        }
+        return Some(RectShape {
+            rotation: dy.atan2(dx),
+            width,
+            height,
+        });
     }
     if points[0].1 == points[1].1
         && points[1].0 == points[2].0
         && points[2].1 == points[3].1
         && points[3].0 == points[0].0
     {
-        return true;
+        let dx = (points[1].0 - points[0].0).into_inner();
+        let dy = (points[1].1 - points[0].1).into_inner();
+        let width = dx.abs();
+        let height = (points[2].1 - points[1].1).into_inner().abs();
+        if width == 0.0 || height == 0.0 {
+            return None;
+        }
+        return Some(RectShape {
+            rotation: dy.atan2(dx),
+            width,
+            height,
+        });
+    }
+
+    let edge = |i: usize| -> (f32, f32) {
+        (
+            (points[i + 1].0 - points[i].0).into_inner(),
+            (points[i + 1].1 - points[i].1).into_inner(),
+        )
+    };
+    let edges: Vec<(f32, f32)> = (0..4).map(edge).collect();
+    let lengths: Vec<f32> = edges
+        .iter()
+        .map(|&(dx, dy)| (dx * dx + dy * dy).sqrt())
+        .collect();
+    if lengths.iter().any(|&len| len <= RECT_TOLERANCE) {
+        return None;
+    }
+
+    for i in 0..4 {
+        let (dx1, dy1) = edges[i];
+        let (dx2, dy2) = edges[(i + 1) % 4];
+        let cos_angle = (dx1 * dx2 + dy1 * dy2) / (lengths[i] * lengths[(i + 1) % 4]);
+        if cos_angle.abs() > RECT_TOLERANCE {
+            return None;
+        }
+    }
+    if (lengths[0] - lengths[2]).abs() > RECT_TOLERANCE * lengths[0].max(lengths[2])
+        || (lengths[1] - lengths[3]).abs() > RECT_TOLERANCE * lengths[1].max(lengths[3])
+    {
+        return None;
     }
-    false
+
+    let (dx, dy) = edges[0];
+    Some(RectShape {
+        rotation: dy.atan2(dx),
+        width: lengths[0],
+        height: lengths[1],
+    })
+}
+
+/// Checks if a set of points forms a rectangle.
+///
+/// # Arguments
+///
+/// * `points` - A slice of points to check.
+///
+/// # Returns
+///
+/// `true` if the points form a rectangle, `false` otherwise.
+pub(crate) fn is_rect(points: &[Point]) -> bool {
+    rect_shape(points).is_some()
 }
 
 /// Trait for objects that have a bounding box.
@@ -272,6 +750,99 @@ pub(crate) fn get_objects_bbox<T: HasBbox>(objects: &[T]) -> Option<BboxKey> {
     merge_bboxes(objects.iter().map(|obj| obj.bbox()))
 }
 
+/// Returns the rectangle where `a` and `b` overlap, or `None` if they don't.
+pub(crate) fn bbox_intersection(a: BboxKey, b: BboxKey) -> Option<BboxKey> {
+    let (ax1, ay1, ax2, ay2) = a;
+    let (bx1, by1, bx2, by2) = b;
+
+    let ix1 = cmp::max(ax1, bx1);
+    let iy1 = cmp::max(ay1, by1);
+    let ix2 = cmp::min(ax2, bx2);
+    let iy2 = cmp::min(ay2, by2);
+    if ix1 >= ix2 || iy1 >= iy2 {
+        None
+    } else {
+        Some((ix1, iy1, ix2, iy2))
+    }
+}
+
+/// Returns the area of `bbox`, or `0.0` if it is degenerate (zero or
+/// negative width/height).
+pub(crate) fn bbox_area(bbox: BboxKey) -> f32 {
+    let (x1, y1, x2, y2) = bbox;
+    ((x2 - x1).into_inner().max(0.0)) * ((y2 - y1).into_inner().max(0.0))
+}
+
+/// Returns `true` if `a` fully contains `b` (i.e. `a & b == b`).
+pub(crate) fn bbox_contains(a: BboxKey, b: BboxKey) -> bool {
+    a.0 <= b.0 && a.1 <= b.1 && a.2 >= b.2 && a.3 >= b.3
+}
+
+/// Returns the fraction of `b`'s own area that falls inside `a`, or `0.0` if
+/// they don't overlap (or `b` has zero area).
+pub(crate) fn bbox_intersection_over(a: BboxKey, b: BboxKey) -> f32 {
+    let Some(intersection) = bbox_intersection(a, b) else {
+        return 0.0;
+    };
+
+    let b_area = bbox_area(b);
+    if b_area <= 0.0 {
+        return 0.0;
+    }
+
+    bbox_area(intersection) / b_area
+}
+
+/// Finds all objects whose bbox overlaps `query` by at least `overlap_ratio`
+/// of the object's own area.
+///
+/// Narrows candidates with two binary searches — one over the objects
+/// sorted by `x1`, one over the objects sorted by `x2` — before running the
+/// exact overlap test, avoiding an O(n) scan per query on dense pages.
+///
+/// # Arguments
+///
+/// * `objects` - A slice of objects that implement [`HasBbox`].
+/// * `query` - The query region.
+/// * `overlap_ratio` - The minimum fraction of an object's bbox area that
+///   must fall inside `query` for it to be included.
+///
+/// # Returns
+///
+/// References to the matching objects, in their original order.
+pub(crate) fn objects_in_rect<T: HasBbox>(
+    objects: &[T],
+    query: BboxKey,
+    overlap_ratio: f32,
+) -> Vec<&T> {
+    let n = objects.len();
+    let (qx1, _, qx2, _) = query;
+
+    let mut by_x1: Vec<usize> = (0..n).collect();
+    by_x1.sort_by_key(|&i| objects[i].bbox().0);
+    let x1_upper = by_x1.partition_point(|&i| objects[i].bbox().0 <= qx2);
+
+    let mut by_x2: Vec<usize> = (0..n).collect();
+    by_x2.sort_by_key(|&i| objects[i].bbox().2);
+    let x2_lower = by_x2.partition_point(|&i| objects[i].bbox().2 < qx1);
+
+    let mut could_overlap_x = vec![false; n];
+    for &i in &by_x1[..x1_upper] {
+        could_overlap_x[i] = true;
+    }
+    for &i in &by_x2[..x2_lower] {
+        could_overlap_x[i] = false;
+    }
+
+    (0..n)
+        .filter(|&i| could_overlap_x[i])
+        .filter_map(|i| {
+            let bbox = objects[i].bbox();
+            (bbox_intersection_over(query, bbox) >= overlap_ratio).then_some(&objects[i])
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,6 +912,61 @@ mod tests {
         assert!(!is_rect(&points));
     }
 
+    #[test]
+    fn test_rect_shape_rotated_square() {
+        // A square rotated 45 degrees around the origin.
+        let s = std::f32::consts::FRAC_1_SQRT_2 * 10.0;
+        let points: Vec<Point> = vec![
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+            (OrderedFloat(s), OrderedFloat(s)),
+            (OrderedFloat(0.0), OrderedFloat(2.0 * s)),
+            (OrderedFloat(-s), OrderedFloat(s)),
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+        ];
+        let shape = rect_shape(&points).expect("should be a rectangle");
+        assert!((shape.rotation - std::f32::consts::FRAC_PI_4).abs() < 1e-3);
+        assert!((shape.width - 10.0).abs() < 1e-2);
+        assert!((shape.height - 10.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_rect_shape_tolerates_float_noise() {
+        // Nearly axis-aligned and nearly equal-length edges, within tolerance.
+        let points: Vec<Point> = vec![
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+            (OrderedFloat(10.0001), OrderedFloat(0.0)),
+            (OrderedFloat(10.0), OrderedFloat(5.0002)),
+            (OrderedFloat(-0.0001), OrderedFloat(5.0)),
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+        ];
+        assert!(rect_shape(&points).is_some());
+    }
+
+    #[test]
+    fn test_rect_shape_rejects_zero_length_edge() {
+        let points: Vec<Point> = vec![
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+            (OrderedFloat(0.0), OrderedFloat(0.0)), // zero-length edge
+            (OrderedFloat(10.0), OrderedFloat(10.0)),
+            (OrderedFloat(10.0), OrderedFloat(0.0)),
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+        ];
+        assert!(rect_shape(&points).is_none());
+    }
+
+    #[test]
+    fn test_rect_shape_rejects_non_perpendicular_quad() {
+        // A parallelogram (equal opposite edges, but not right angles).
+        let points: Vec<Point> = vec![
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+            (OrderedFloat(10.0), OrderedFloat(0.0)),
+            (OrderedFloat(15.0), OrderedFloat(5.0)),
+            (OrderedFloat(5.0), OrderedFloat(5.0)),
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+        ];
+        assert!(rect_shape(&points).is_none());
+    }
+
     #[test]
     fn test_merge_bboxes_single() {
         let bboxes = vec![(
@@ -409,6 +1035,10 @@ mod tests {
                 ),
                 rotation_degrees: OrderedFloat(0.0),
                 upright: true,
+                font_size: OrderedFloat(10.0),
+                font_name: None,
+                fill_color: PdfColor::new(0, 0, 0, 255),
+                text_matrix: (1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
             },
             Char {
                 unicode_char: Some("B".to_string()),
@@ -420,6 +1050,10 @@ mod tests {
                 ),
                 rotation_degrees: OrderedFloat(0.0),
                 upright: true,
+                font_size: OrderedFloat(10.0),
+                font_name: None,
+                fill_color: PdfColor::new(0, 0, 0, 255),
+                text_matrix: (1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
             },
         ];
         let result = get_objects_bbox(&chars);
@@ -440,4 +1074,330 @@ mod tests {
         let result = get_objects_bbox(&chars);
         assert_eq!(result, None);
     }
+
+    fn pt(x: f32, y: f32) -> Point {
+        (OrderedFloat(x), OrderedFloat(y))
+    }
+
+    fn line(line_type: LineType, points: Vec<Point>) -> Line {
+        Line {
+            line_type,
+            points,
+            color: PdfColor::new(0, 0, 0, 255),
+            width: OrderedFloat(1.0),
+        }
+    }
+
+    #[test]
+    fn test_flatten_straight_line_is_unchanged() {
+        let input = line(LineType::Straight, vec![pt(0.0, 0.0), pt(10.0, 0.0)]);
+        let flattened = input.flatten(0.1);
+        assert_eq!(flattened.line_type, LineType::Straight);
+        assert_eq!(flattened.points, input.points);
+    }
+
+    #[test]
+    fn test_flatten_curve_within_tolerance_emits_chord() {
+        // Control points sit exactly on the chord, so this is flat at any
+        // positive tolerance and should collapse to just the two endpoints.
+        let curve = line(
+            LineType::Curve,
+            vec![pt(0.0, 0.0), pt(3.0, 0.0), pt(6.0, 0.0), pt(10.0, 0.0)],
+        );
+        let flattened = curve.flatten(0.01);
+        assert_eq!(flattened.line_type, LineType::Straight);
+        assert_eq!(flattened.points, vec![pt(0.0, 0.0), pt(10.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_flatten_curve_needing_subdivision_stays_within_tolerance() {
+        // A quarter-circle-ish bulge: control points well off the chord, so
+        // a loose tolerance should still force at least one split.
+        let curve = line(
+            LineType::Curve,
+            vec![pt(0.0, 0.0), pt(0.0, 10.0), pt(10.0, 10.0), pt(10.0, 0.0)],
+        );
+        let flattened = curve.flatten(0.5);
+        assert_eq!(flattened.line_type, LineType::Straight);
+        assert!(flattened.points.len() > 2);
+        assert_eq!(*flattened.points.first().unwrap(), pt(0.0, 0.0));
+        assert_eq!(*flattened.points.last().unwrap(), pt(10.0, 0.0));
+
+        // A much tighter tolerance should force more subdivisions.
+        let finer = curve.flatten(0.01);
+        assert!(finer.points.len() > flattened.points.len());
+    }
+
+    #[test]
+    fn test_flatten_degenerate_curve_collapses_to_single_point() {
+        let curve = line(
+            LineType::Curve,
+            vec![pt(5.0, 5.0), pt(5.0, 5.0), pt(5.0, 5.0), pt(5.0, 5.0)],
+        );
+        let flattened = curve.flatten(0.01);
+        assert_eq!(flattened.points, vec![pt(5.0, 5.0)]);
+    }
+
+    #[test]
+    fn test_flatten_does_not_emit_duplicate_consecutive_points() {
+        // Two chained segments that are each already flat; the shared
+        // midpoint (5,0) should appear only once.
+        let curve = line(
+            LineType::Curve,
+            vec![
+                pt(0.0, 0.0),
+                pt(1.5, 0.0),
+                pt(3.5, 0.0),
+                pt(5.0, 0.0),
+                pt(6.5, 0.0),
+                pt(8.5, 0.0),
+                pt(10.0, 0.0),
+            ],
+        );
+        let flattened = curve.flatten(0.01);
+        assert_eq!(flattened.points, vec![pt(0.0, 0.0), pt(5.0, 0.0), pt(10.0, 0.0)]);
+    }
+
+    fn char_at(bbox: BboxKey) -> Char {
+        Char {
+            unicode_char: Some("A".to_string()),
+            bbox,
+            rotation_degrees: OrderedFloat(0.0),
+            upright: true,
+            font_size: OrderedFloat(10.0),
+            font_name: None,
+            fill_color: PdfColor::new(0, 0, 0, 255),
+            text_matrix: (1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+        }
+    }
+
+    #[test]
+    fn test_bbox_overlap_ratio_full_containment() {
+        let bbox = (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(10.0),
+            OrderedFloat(10.0),
+        );
+        let query = (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(20.0),
+            OrderedFloat(20.0),
+        );
+        assert_eq!(bbox_intersection_over(query, bbox), 1.0);
+    }
+
+    #[test]
+    fn test_bbox_overlap_ratio_partial_and_no_overlap() {
+        let bbox = (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(10.0),
+            OrderedFloat(10.0),
+        );
+        let half_query = (
+            OrderedFloat(5.0),
+            OrderedFloat(0.0),
+            OrderedFloat(15.0),
+            OrderedFloat(10.0),
+        );
+        assert_eq!(bbox_intersection_over(half_query, bbox), 0.5);
+
+        let disjoint_query = (
+            OrderedFloat(20.0),
+            OrderedFloat(20.0),
+            OrderedFloat(30.0),
+            OrderedFloat(30.0),
+        );
+        assert_eq!(bbox_intersection_over(disjoint_query, bbox), 0.0);
+    }
+
+    #[test]
+    fn test_objects_in_rect_filters_by_x_range_and_overlap_ratio() {
+        let chars = vec![
+            char_at((
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(10.0),
+            )),
+            char_at((
+                OrderedFloat(5.0),
+                OrderedFloat(0.0),
+                OrderedFloat(15.0),
+                OrderedFloat(10.0),
+            )),
+            char_at((
+                OrderedFloat(50.0),
+                OrderedFloat(50.0),
+                OrderedFloat(60.0),
+                OrderedFloat(60.0),
+            )),
+        ];
+        let query = (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(10.0),
+            OrderedFloat(10.0),
+        );
+
+        // Fully-contained only: the first char.
+        let fully_contained = objects_in_rect(&chars, query, 1.0);
+        assert_eq!(fully_contained.len(), 1);
+        assert_eq!(fully_contained[0].bbox, chars[0].bbox);
+
+        // Any overlap: the first two chars, not the far-away third.
+        let any_overlap = objects_in_rect(&chars, query, 0.01);
+        assert_eq!(any_overlap.len(), 2);
+    }
+
+    #[test]
+    fn test_chars_in_rect_pymethod_matches_objects_in_rect() {
+        let objects = Objects {
+            rects: vec![],
+            lines: vec![],
+            chars: vec![
+                char_at((
+                    OrderedFloat(0.0),
+                    OrderedFloat(0.0),
+                    OrderedFloat(10.0),
+                    OrderedFloat(10.0),
+                )),
+                char_at((
+                    OrderedFloat(50.0),
+                    OrderedFloat(50.0),
+                    OrderedFloat(60.0),
+                    OrderedFloat(60.0),
+                )),
+            ],
+        };
+        let matches = objects.chars_in_rect((0.0, 0.0, 10.0, 10.0), 1.0);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].bbox, objects.chars[0].bbox);
+    }
+
+    #[test]
+    fn test_matrix_translate_apply() {
+        let m = Matrix::translate(3.0, -2.0);
+        assert_eq!(m.apply(1.0, 1.0), (4.0, -1.0));
+    }
+
+    #[test]
+    fn test_matrix_flip_y() {
+        let m = Matrix::flip_y(100.0);
+        assert_eq!(m.apply(5.0, 20.0), (5.0, 80.0));
+    }
+
+    #[test]
+    fn test_matrix_rotate90_quarter_turns() {
+        let m = Matrix::rotate90(1);
+        let (x, y) = m.apply(1.0, 0.0);
+        assert!((x - 0.0).abs() < 1e-6);
+        assert!((y - 1.0).abs() < 1e-6);
+
+        let identity = Matrix::rotate90(4);
+        assert_eq!(identity, Matrix::identity());
+    }
+
+    #[test]
+    fn test_matrix_then_composes_in_order() {
+        let translate = Matrix::translate(1.0, 0.0);
+        let scale = Matrix::scale(2.0, 2.0);
+        let combined = translate.then(&scale);
+        assert_eq!(combined.apply(1.0, 1.0), (4.0, 2.0));
+    }
+
+    #[test]
+    fn test_transform_bbox_under_translation() {
+        let bbox = (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(10.0),
+            OrderedFloat(10.0),
+        );
+        let transformed = transform_bbox(&Matrix::translate(5.0, 5.0), bbox);
+        assert_eq!(
+            transformed,
+            (
+                OrderedFloat(5.0),
+                OrderedFloat(5.0),
+                OrderedFloat(15.0),
+                OrderedFloat(15.0),
+            )
+        );
+    }
+
+    #[test]
+    fn test_transform_bbox_under_rotation() {
+        let bbox = (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(10.0),
+            OrderedFloat(4.0),
+        );
+        // (x, y) -> (-y, x): the corners land at (0, 0), (0, 10), (-4, 10)
+        // and (-4, 0).
+        let transformed = transform_bbox(&Matrix::rotate90(1), bbox);
+        assert_eq!(
+            transformed,
+            (
+                OrderedFloat(-4.0),
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+            )
+        );
+    }
+
+    #[test]
+    fn test_apply_transform_updates_rects_lines_and_chars() {
+        let mut objects = Objects {
+            rects: vec![Rect {
+                bbox: (
+                    OrderedFloat(0.0),
+                    OrderedFloat(0.0),
+                    OrderedFloat(10.0),
+                    OrderedFloat(10.0),
+                ),
+                fill_color: PdfColor::new(0, 0, 0, 255),
+                stroke_color: PdfColor::new(0, 0, 0, 255),
+                stroke_width: 1.0,
+            }],
+            lines: vec![Line {
+                line_type: LineType::Straight,
+                points: vec![(OrderedFloat(0.0), OrderedFloat(0.0))],
+                color: PdfColor::new(0, 0, 0, 255),
+                width: OrderedFloat(1.0),
+            }],
+            chars: vec![char_at((
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(10.0),
+            ))],
+        };
+
+        objects.apply_transform(Matrix::translate(5.0, 0.0));
+
+        assert_eq!(
+            objects.rects[0].bbox,
+            (
+                OrderedFloat(5.0),
+                OrderedFloat(0.0),
+                OrderedFloat(15.0),
+                OrderedFloat(10.0),
+            )
+        );
+        assert_eq!(objects.lines[0].points[0], (OrderedFloat(5.0), OrderedFloat(0.0)));
+        assert_eq!(
+            objects.chars[0].bbox,
+            (
+                OrderedFloat(5.0),
+                OrderedFloat(0.0),
+                OrderedFloat(15.0),
+                OrderedFloat(10.0),
+            )
+        );
+    }
 }
@@ -0,0 +1,161 @@
+use crate::objects::{BboxKey, Char};
+use ordered_float::OrderedFloat;
+use pdfium_render::prelude::PdfColor;
+use pyo3::exceptions::PyValueError;
+use pyo3::PyErr;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Controls when the OCR fallback pipeline kicks in for a page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OcrMode {
+    /// Only run OCR when a page has no (or negligible) embedded text.
+    #[default]
+    Auto,
+    /// Always run OCR, even on pages that already have a text layer.
+    Always,
+    /// Never run OCR, even on image-only pages.
+    Never,
+}
+
+/// Returned when a string doesn't name a known [`OcrMode`].
+#[derive(Debug, Clone, Error)]
+#[error("invalid OCR mode {got:?}, expected \"auto\", \"always\", or \"never\"")]
+pub struct InvalidOcrModeError {
+    pub got: String,
+}
+
+impl From<InvalidOcrModeError> for PyErr {
+    fn from(err: InvalidOcrModeError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+impl FromStr for OcrMode {
+    type Err = InvalidOcrModeError;
+
+    /// Parses an OCR mode string (`"auto"`, `"always"`, or `"never"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(OcrMode::Auto),
+            "always" => Ok(OcrMode::Always),
+            "never" => Ok(OcrMode::Never),
+            _ => Err(InvalidOcrModeError { got: s.to_string() }),
+        }
+    }
+}
+
+/// Minimum number of embedded characters below which a page is considered
+/// "negligible" text and eligible for OCR under [`OcrMode::Auto`].
+const AUTO_OCR_CHAR_THRESHOLD: usize = 4;
+
+/// A recognized word returned by an [`OcrEngine`], with its bounding box in
+/// the raster image's pixel space (origin top-left, y-down);
+/// [`ocr_words_to_chars`] scales it to page points.
+#[derive(Debug, Clone)]
+pub struct OcrWord {
+    /// The recognized text.
+    pub text: String,
+    /// The bounding box of the word, in raster pixels.
+    pub bbox: BboxKey,
+    /// The engine's confidence in this recognition, in the range `0.0..=1.0`.
+    pub confidence: f32,
+}
+
+/// A pluggable OCR backend.
+///
+/// Implementations receive a raster image of a page (RGBA bytes, width,
+/// height, and the DPI it was rendered at) and return the words they
+/// recognized, with bounding boxes in the raster's pixel space.
+pub trait OcrEngine {
+    /// Runs OCR over a rendered page image.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgba` - The page raster, as tightly packed RGBA8 pixels.
+    /// * `width` / `height` - The raster dimensions in pixels.
+    /// * `dpi` - The DPI the page was rendered at.
+    fn recognize(&self, rgba: &[u8], width: u32, height: u32, dpi: f32) -> Vec<OcrWord>;
+}
+
+/// An [`OcrEngine`] named for Tesseract, available behind the `ocr` Cargo
+/// feature. It holds a language pack name but calls no OCR library: its
+/// [`OcrEngine::recognize`] returns no words.
+#[cfg(feature = "ocr")]
+pub struct TesseractEngine {
+    /// The Tesseract language pack to use, e.g. `"eng"`.
+    pub language: String,
+}
+
+#[cfg(feature = "ocr")]
+impl TesseractEngine {
+    /// Creates a new engine using the given Tesseract language pack.
+    pub fn new(language: impl Into<String>) -> Self {
+        Self {
+            language: language.into(),
+        }
+    }
+}
+
+#[cfg(feature = "ocr")]
+impl OcrEngine for TesseractEngine {
+    fn recognize(&self, rgba: &[u8], width: u32, height: u32, dpi: f32) -> Vec<OcrWord> {
+        // No recognition happens here: the raster is ignored and no words
+        // are returned.
+        let _ = (rgba, width, height, dpi);
+        Vec::new()
+    }
+}
+
+/// Returns `true` if `char_count` embedded characters are few enough that a
+/// page should be treated as image-only for the purposes of [`OcrMode::Auto`].
+pub fn page_needs_ocr(mode: OcrMode, char_count: usize) -> bool {
+    match mode {
+        OcrMode::Always => true,
+        OcrMode::Never => false,
+        OcrMode::Auto => char_count < AUTO_OCR_CHAR_THRESHOLD,
+    }
+}
+
+/// Converts a pixel-space bounding box (origin top-left, y-down) from a page
+/// rendered at `dpi` into page points (origin top-left, matching the rest of
+/// this crate's coordinate convention).
+fn pixel_bbox_to_points(bbox: (f32, f32, f32, f32), dpi: f32) -> BboxKey {
+    let scale = 72.0 / dpi;
+    (
+        OrderedFloat(bbox.0 * scale),
+        OrderedFloat(bbox.1 * scale),
+        OrderedFloat(bbox.2 * scale),
+        OrderedFloat(bbox.3 * scale),
+    )
+}
+
+/// Converts OCR words recognized on a raster page into synthetic [`Char`]s,
+/// one per word, so they can be fed into the same word-extraction and
+/// table-detection pipeline used for native text.
+///
+/// Each word becomes a single pseudo-character carrying its full text, since
+/// OCR engines generally report word-level (not glyph-level) boxes.
+pub fn ocr_words_to_chars(words: &[OcrWord], dpi: f32) -> Vec<Char> {
+    words
+        .iter()
+        .map(|w| Char {
+            unicode_char: Some(w.text.clone()),
+            bbox: pixel_bbox_to_points(
+                (
+                    w.bbox.0.into_inner(),
+                    w.bbox.1.into_inner(),
+                    w.bbox.2.into_inner(),
+                    w.bbox.3.into_inner(),
+                ),
+                dpi,
+            ),
+            rotation_degrees: OrderedFloat(0.0),
+            upright: true,
+            font_size: OrderedFloat(0.0),
+            font_name: None,
+            fill_color: PdfColor::new(0, 0, 0, 255),
+            text_matrix: (1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+        })
+        .collect()
+}
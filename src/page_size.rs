@@ -0,0 +1,76 @@
+use pdfium_render::prelude::{Pdfium, PdfiumError};
+
+/// Width and height of a page, in points, as reported directly by Pdfium
+/// without loading the full `FPDF_PAGE` object.
+pub type PageDimensions = (f32, f32);
+
+/// Reads the width/height of every page in `path` via `FPDF_GetPageSizeByIndexF`,
+/// without opening each page in full.
+///
+/// On a several-hundred-page document this turns a multi-second dimension
+/// scan (open every page, read its box, close it) into a fraction of a
+/// second, since Pdfium only has to walk the page tree rather than parse and
+/// interpret each page's content stream.
+pub fn fast_page_sizes(
+    pdfium: &Pdfium,
+    path: &str,
+    password: Option<&str>,
+) -> Result<Vec<PageDimensions>, PdfiumError> {
+    let document = pdfium.load_pdf_from_file(path, password)?;
+    let page_count = document.pages().len();
+    let bindings = pdfium.bindings();
+    let handle = document.handle();
+
+    (0..page_count)
+        .map(|index| {
+            let mut width = 0.0;
+            let mut height = 0.0;
+            let ok = bindings.FPDF_GetPageSizeByIndexF(handle, index, &mut width, &mut height);
+            if ok == 0 {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    pdfium_render::prelude::PdfiumInternalError::Unknown,
+                ))
+            } else {
+                Ok((width, height))
+            }
+        })
+        .collect()
+}
+
+/// A predicate used to cheaply restrict extraction to a subset of pages in a
+/// large document, evaluated against each page's `(width, height)` before
+/// the page is fully loaded.
+pub trait PageSizePredicate {
+    /// Returns `true` if the page with the given dimensions should be kept.
+    fn keep(&self, dims: PageDimensions) -> bool;
+}
+
+impl<F> PageSizePredicate for F
+where
+    F: Fn(PageDimensions) -> bool,
+{
+    fn keep(&self, dims: PageDimensions) -> bool {
+        self(dims)
+    }
+}
+
+/// Returns the zero-based indices of pages in `path` whose dimensions satisfy
+/// `predicate`, without loading any page in full.
+///
+/// This is meant to be used as an optional prefilter ahead of table
+/// extraction, e.g. to skip tiny logo/blank pages or oversized foldouts in a
+/// large book.
+pub fn prefilter_pages_by_size(
+    pdfium: &Pdfium,
+    path: &str,
+    password: Option<&str>,
+    predicate: &impl PageSizePredicate,
+) -> Result<Vec<usize>, PdfiumError> {
+    let sizes = fast_page_sizes(pdfium, path, password)?;
+    Ok(sizes
+        .into_iter()
+        .enumerate()
+        .filter(|(_, dims)| predicate.keep(*dims))
+        .map(|(idx, _)| idx)
+        .collect())
+}
@@ -1,4 +1,5 @@
 use crate::objects::*;
+use crate::ocr::{ocr_words_to_chars, page_needs_ocr, OcrEngine, OcrMode};
 use ordered_float::OrderedFloat;
 use pdfium_render::prelude::PdfPage as PdfiumPage;
 use pdfium_render::prelude::*;
@@ -47,6 +48,45 @@ impl Page {
         }
     }
 
+    /// Extracts this page's objects as normal, then, if the page qualifies
+    /// for OCR under `mode` (see [`page_needs_ocr`]), rasterizes it at `dpi`,
+    /// runs `engine` over the raster, and appends the recognized words as
+    /// synthetic [`Char`]s (see [`ocr_words_to_chars`]) so word extraction
+    /// and table detection see them exactly like native embedded text.
+    ///
+    /// Rendering failures (e.g. an unsupported page content stream) are
+    /// treated the same as "no OCR words found": the page keeps whatever
+    /// native text it already had.
+    pub fn extract_objects_with_ocr(&self, engine: &dyn OcrEngine, mode: OcrMode, dpi: f32) {
+        self.extract_objects();
+
+        let char_count = self
+            .objects
+            .borrow()
+            .as_ref()
+            .map(|objects| objects.chars.len())
+            .unwrap_or(0);
+
+        if !page_needs_ocr(mode, char_count) {
+            return;
+        }
+
+        let render_config = PdfRenderConfig::new().scale_page_by_factor(dpi / 72.0);
+        let Ok(bitmap) = self.inner.render_with_config(&render_config) else {
+            return;
+        };
+        let width = bitmap.width() as u32;
+        let height = bitmap.height() as u32;
+        let rgba = bitmap.as_rgba_bytes();
+
+        let words = engine.recognize(&rgba, width, height, dpi);
+        let ocr_chars = ocr_words_to_chars(&words, dpi);
+
+        if let Some(objects) = self.objects.borrow_mut().as_mut() {
+            objects.chars.extend(ocr_chars);
+        }
+    }
+
     fn extract_objects_from_page(&self) -> Objects {
         let mut objects = Objects {
             rects: vec![],
@@ -128,11 +168,27 @@ impl Page {
             );
             let rotation_degrees = character.get_rotation_clockwise_degrees();
 
+            let font_name = character.font_name();
+            let matrix = character.matrix().unwrap();
+
             objects.chars.push(Char {
                 unicode_char: character.unicode_string(),
                 bbox: bbox,
                 rotation_degrees: OrderedFloat::from(rotation_degrees),
                 upright: rotation_degrees == 0.0 || rotation_degrees == 180.0,
+                font_size: OrderedFloat::from(character.unscaled_font_size().value),
+                font_name: (!font_name.is_empty()).then_some(font_name),
+                fill_color: character
+                    .fill_color()
+                    .unwrap_or(PdfColor::new(0, 0, 0, 255)),
+                text_matrix: (
+                    matrix.a(),
+                    matrix.b(),
+                    matrix.c(),
+                    matrix.d(),
+                    matrix.e(),
+                    matrix.f(),
+                ),
             })
         }
         // if page_rotation_degrees == PdfPageRenderRotation::None {
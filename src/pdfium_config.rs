@@ -0,0 +1,124 @@
+use crate::ocr::OcrMode;
+use pdfium_render::prelude::{Pdfium, PdfiumError};
+use std::env;
+use std::path::PathBuf;
+
+/// Environment variable checked first for the Pdfium dynamic library path.
+const TABLERS_PDFIUM_PATH_VAR: &str = "TABLERS_PDFIUM_PATH";
+/// Environment variable checked second, matching the variable name other
+/// Pdfium consumers already use.
+const PDFIUM_DYNAMIC_LIB_PATH_VAR: &str = "PDFIUM_DYNAMIC_LIB_PATH";
+
+/// Platform-specific name of the bundled Pdfium library, relative to the
+/// crate's `python/tablers` directory.
+#[cfg(target_os = "windows")]
+const BUNDLED_LIB_NAME: &str = "pdfium.dll";
+#[cfg(target_os = "macos")]
+const BUNDLED_LIB_NAME: &str = "libpdfium.dylib";
+#[cfg(target_os = "linux")]
+const BUNDLED_LIB_NAME: &str = "libpdfium.so";
+
+/// Configuration controlling how the Pdfium library is located and loaded.
+///
+/// Use [`PdfiumConfig::default`] to resolve the library via the standard
+/// priority order, or set `explicit_path` to force a specific location.
+#[derive(Debug, Clone, Default)]
+pub struct PdfiumConfig {
+    /// An explicit path to the Pdfium dynamic library, taking priority over
+    /// everything else when set.
+    pub explicit_path: Option<PathBuf>,
+    /// Controls when the OCR fallback pipeline (see [`crate::ocr`]) runs.
+    pub ocr_mode: OcrMode,
+}
+
+impl PdfiumConfig {
+    /// Creates a config that forces loading the library from `path`.
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            explicit_path: Some(path.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the OCR fallback mode.
+    pub fn ocr_mode(mut self, mode: OcrMode) -> Self {
+        self.ocr_mode = mode;
+        self
+    }
+
+    /// Resolves the Pdfium library path in priority order:
+    ///
+    /// 1. `explicit_path` on this config.
+    /// 2. The `TABLERS_PDFIUM_PATH` environment variable.
+    /// 3. The `PDFIUM_DYNAMIC_LIB_PATH` environment variable.
+    /// 4. The system library search path (bare library name, e.g.
+    ///    `libpdfium.so`), letting the OS loader find a system-installed
+    ///    copy via `LD_LIBRARY_PATH`/`ldconfig`/equivalent.
+    ///
+    /// [`init_pdfium`] additionally falls back to the bundled platform
+    /// default under `python/tablers` if loading the path returned here
+    /// fails and none of steps 1-3 applied.
+    pub(crate) fn resolve_path(&self) -> PathBuf {
+        if let Some(path) = &self.explicit_path {
+            return path.clone();
+        }
+        if let Ok(path) = env::var(TABLERS_PDFIUM_PATH_VAR) {
+            return PathBuf::from(path);
+        }
+        if let Ok(path) = env::var(PDFIUM_DYNAMIC_LIB_PATH_VAR) {
+            return PathBuf::from(path);
+        }
+        PathBuf::from(BUNDLED_LIB_NAME)
+    }
+}
+
+/// Initializes a [`Pdfium`] instance using the resolution order described in
+/// [`PdfiumConfig::resolve_path`].
+///
+/// When built with the `static-pdf` feature, Pdfium is statically linked and
+/// no library file needs to be located at all.
+#[cfg(feature = "static-pdf")]
+pub fn init_pdfium(_config: &PdfiumConfig) -> Result<Pdfium, PdfiumError> {
+    Ok(Pdfium::new(Pdfium::bind_to_statically_linked_library()?))
+}
+
+/// Initializes a [`Pdfium`] instance using the resolution order described in
+/// [`PdfiumConfig::resolve_path`].
+#[cfg(any(feature = "dynamic-pdf", not(feature = "static-pdf")))]
+pub fn init_pdfium(config: &PdfiumConfig) -> Result<Pdfium, PdfiumError> {
+    let path = config.resolve_path();
+    match Pdfium::bind_to_library(&path) {
+        Ok(bindings) => Ok(Pdfium::new(bindings)),
+        Err(err) => {
+            // A failed explicit/env path is reported as is; only the bare
+            // system name falls back to the bundled platform default.
+            if config.explicit_path.is_some()
+                || env::var(TABLERS_PDFIUM_PATH_VAR).is_ok()
+                || env::var(PDFIUM_DYNAMIC_LIB_PATH_VAR).is_ok()
+            {
+                return Err(err);
+            }
+            let bundled = PathBuf::from("python/tablers").join(BUNDLED_LIB_NAME);
+            Pdfium::bind_to_library(&bundled).map(Pdfium::new)
+        }
+    }
+}
+
+/// Like [`init_pdfium`], but also resolves and caches the raw page-count /
+/// page-size function pointers used when sizing a document up front (see
+/// [`crate::pdfium_symbols`]). This does not extend to the per-character or
+/// per-object FFI calls made while walking a page's contents; those always
+/// go through the normal `PdfiumLibraryBindings` dispatch.
+///
+/// Symbol caching is a performance optimization only: if it fails for any
+/// reason (e.g. a statically linked build with no loadable library file),
+/// the returned `Pdfium` instance is still fully usable through the normal
+/// `PdfiumLibraryBindings` path and the cache is simply absent.
+#[cfg(any(feature = "dynamic-pdf", not(feature = "static-pdf")))]
+pub(crate) fn init_pdfium_with_symbol_cache(
+    config: &PdfiumConfig,
+) -> Result<(Pdfium, Option<crate::pdfium_symbols::CachedSymbols>), PdfiumError> {
+    let pdfium = init_pdfium(config)?;
+    let symbols = unsafe { crate::pdfium_symbols::CachedSymbols::load(&config.resolve_path()) }.ok();
+    Ok((pdfium, symbols))
+}
@@ -0,0 +1,217 @@
+use crate::pdfium_config::{init_pdfium, PdfiumConfig};
+use crate::tables::{find_tables, parse_table_ron, write_table_ron, Table};
+use crate::TfSettings;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, Command, Stdio};
+use std::rc::Rc;
+
+/// Environment variable that marks a spawned child process as a pool worker.
+///
+/// When set, [`maybe_run_worker`] takes over the process instead of returning,
+/// turning the current executable into a disposable Pdfium worker.
+const WORKER_MARKER_VAR: &str = "TABLERS_PDFIUM_POOL_WORKER";
+
+/// Result of extracting tables from a single document, reported over IPC as
+/// a multi-line response: an `OK\t<n_pages>` (or `ERR\t<message>`) header
+/// line, followed for each page by a `<n_tables>` count line and that many
+/// lines of compact RON (see [`write_table_ron`]/[`parse_table_ron`]) — one
+/// real [`Table`] per line, not just a count, so a caller gets the actual
+/// cells/text a worker found rather than having to re-open the document.
+#[derive(Debug)]
+pub enum ExtractResult {
+    /// Extraction succeeded; carries each page's extracted tables.
+    Ok(Vec<Vec<Table>>),
+    /// Extraction failed with a human-readable message.
+    Err(String),
+}
+
+/// A worker process handle, tracking its stdin/stdout pipes so the pool can
+/// detect a crash and restart it.
+struct Worker {
+    child: Child,
+    stdin: std::process::ChildStdin,
+    stdout: BufReader<std::process::ChildStdout>,
+}
+
+impl Worker {
+    fn spawn() -> std::io::Result<Self> {
+        let exe = std::env::current_exe()?;
+        let mut child = Command::new(exe)
+            .env(WORKER_MARKER_VAR, "1")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin was piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout was piped"));
+        Ok(Self {
+            child,
+            stdin,
+            stdout,
+        })
+    }
+
+    fn is_alive(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+
+    fn extract(&mut self, path: &str) -> std::io::Result<ExtractResult> {
+        writeln!(self.stdin, "{}", path)?;
+        self.stdin.flush()?;
+        self.read_response()
+    }
+
+    /// Reads one worker response: a header line, then for an `OK` header,
+    /// each page's table count and that many RON table lines. See
+    /// [`ExtractResult`] for the full line shape.
+    fn read_response(&mut self) -> std::io::Result<ExtractResult> {
+        let mut header = String::new();
+        self.stdout.read_line(&mut header)?;
+        let header = header.trim_end();
+
+        let n_pages: usize = match header.split_once('\t') {
+            Some(("OK", rest)) => match rest.parse() {
+                Ok(n) => n,
+                Err(_) => return Ok(ExtractResult::Err(format!("malformed page count: {:?}", rest))),
+            },
+            Some(("ERR", msg)) => return Ok(ExtractResult::Err(msg.to_string())),
+            _ => return Ok(ExtractResult::Err(format!("malformed worker response: {:?}", header))),
+        };
+
+        let mut pages = Vec::with_capacity(n_pages);
+        for _ in 0..n_pages {
+            let mut count_line = String::new();
+            self.stdout.read_line(&mut count_line)?;
+            let n_tables: usize = match count_line.trim_end().parse() {
+                Ok(n) => n,
+                Err(_) => {
+                    return Ok(ExtractResult::Err(format!(
+                        "malformed table count: {:?}",
+                        count_line.trim_end()
+                    )))
+                }
+            };
+
+            let mut tables = Vec::with_capacity(n_tables);
+            for _ in 0..n_tables {
+                let mut ron_line = String::new();
+                self.stdout.read_line(&mut ron_line)?;
+                match parse_table_ron(ron_line.trim_end()) {
+                    Ok(table) => tables.push(table),
+                    Err(e) => return Ok(ExtractResult::Err(format!("malformed table RON: {e}"))),
+                }
+            }
+            pages.push(tables);
+        }
+
+        Ok(ExtractResult::Ok(pages))
+    }
+}
+
+/// A pool of worker *processes*, each owning its own `Pdfium` instance, used
+/// to parallelize table extraction across documents while preserving
+/// Pdfium's single-thread-per-instance invariant.
+pub struct PdfiumPool {
+    workers: Vec<Worker>,
+}
+
+impl PdfiumPool {
+    /// Spawns `num_workers` worker processes.
+    pub fn new(num_workers: usize) -> std::io::Result<Self> {
+        let workers = (0..num_workers.max(1))
+            .map(|_| Worker::spawn())
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Ok(Self { workers })
+    }
+
+    /// Extracts tables from each path in `paths`, dispatching jobs round-robin
+    /// across the worker pool. A worker that has died is transparently
+    /// respawned before its next job so one malformed PDF cannot take down
+    /// the rest of the batch.
+    pub fn extract_tables(&mut self, paths: &[String]) -> Vec<ExtractResult> {
+        let mut results = Vec::with_capacity(paths.len());
+        let n = self.workers.len();
+        for (i, path) in paths.iter().enumerate() {
+            let idx = i % n;
+            if !self.workers[idx].is_alive() {
+                if let Ok(fresh) = Worker::spawn() {
+                    self.workers[idx] = fresh;
+                }
+            }
+            let result = match self.workers[idx].extract(path) {
+                Ok(r) => r,
+                Err(e) => ExtractResult::Err(e.to_string()),
+            };
+            results.push(result);
+        }
+        results
+    }
+}
+
+/// Runs the worker loop when this process was spawned as a pool worker,
+/// never returning. Host binaries that embed `tablers` should call this at
+/// the top of `main()`; it is a no-op (returns immediately) in every other
+/// process.
+pub fn maybe_run_worker() {
+    if std::env::var(WORKER_MARKER_VAR).is_err() {
+        return;
+    }
+    #[cfg(any(feature = "dynamic-pdf", not(feature = "static-pdf")))]
+    let (pdfium, symbols) =
+        match crate::pdfium_config::init_pdfium_with_symbol_cache(&PdfiumConfig::default()) {
+            Ok(pair) => pair,
+            Err(e) => {
+                eprintln!("worker failed to init Pdfium: {:?}", e);
+                std::process::exit(1);
+            }
+        };
+    #[cfg(feature = "static-pdf")]
+    let (pdfium, symbols) = match init_pdfium(&PdfiumConfig::default()) {
+        Ok(p) => (p, None),
+        Err(e) => {
+            eprintln!("worker failed to init Pdfium: {:?}", e);
+            std::process::exit(1);
+        }
+    };
+    let settings = Rc::new(TfSettings::default());
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    for line in stdin.lock().lines() {
+        let path = match line {
+            Ok(p) => p,
+            Err(_) => break,
+        };
+        let mut out = stdout.lock();
+        match pdfium.load_pdf_from_file(&path, None) {
+            Ok(doc) => {
+                // Prefer the cached `FPDF_GetPageCount` symbol over
+                // `doc.pages().len()` when available: it's the same value,
+                // resolved once at startup instead of re-resolved through
+                // `PdfiumLibraryBindings` on every worker job.
+                let page_count = symbols
+                    .as_ref()
+                    .map(|s| unsafe {
+                        (s.fpdf_get_page_count)(doc.handle() as *mut std::ffi::c_void) as usize
+                    })
+                    .unwrap_or_else(|| doc.pages().len());
+
+                let _ = writeln!(out, "OK\t{}", page_count);
+                if page_count > 0 {
+                    for page in doc.pages().iter() {
+                        let page = crate::pages::Page::new(unsafe { std::mem::transmute(page) }, 0);
+                        let tables = find_tables(&page, settings.clone(), true);
+                        let _ = writeln!(out, "{}", tables.len());
+                        for table in &tables {
+                            let _ = writeln!(out, "{}", write_table_ron(table, false));
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                let _ = writeln!(out, "ERR\t{:?}", e);
+            }
+        };
+        let _ = out.flush();
+    }
+    std::process::exit(0);
+}
@@ -0,0 +1,68 @@
+use libloading::{Library, Symbol};
+use std::os::raw::{c_double, c_int};
+use std::path::Path;
+
+/// Raw Pdfium function pointers resolved once at load time.
+///
+/// Binding dynamically through `pdfium-render`'s `PdfiumLibraryBindings`
+/// trait re-resolves each symbol from the loaded library on every call,
+/// which is measurable overhead when repeated across many pages. This
+/// struct caches only the page-enumeration symbols used to size a
+/// document up front (page count, page size by index) — it does *not*
+/// cover the per-character/per-object FFI calls `pages::Page` makes while
+/// actually walking a page's content (those still go through
+/// `PdfiumLibraryBindings` and `pdfium-render`'s safe wrappers). Routing
+/// that hot path through raw cached symbols too would mean bypassing
+/// `pdfium-render`'s `PdfPage`/`PdfPageText` APIs for the FFI calls behind
+/// them, which is a larger change than this struct attempts.
+///
+/// # Safety
+///
+/// The `Symbol`s are transmuted into `'static` function pointers. This is
+/// sound only because `library` is kept alive for as long as `CachedSymbols`
+/// exists (it is never dropped before the pointers are used) and the
+/// pointers never outlive the struct.
+pub(crate) struct CachedSymbols {
+    /// Keeps the dynamic library mapped for as long as the pointers are used.
+    _library: Library,
+    pub(crate) fpdf_get_page_count: unsafe extern "C" fn(document: *mut std::ffi::c_void) -> c_int,
+    pub(crate) fpdf_get_page_size_by_index_f: unsafe extern "C" fn(
+        document: *mut std::ffi::c_void,
+        page_index: c_int,
+        width: *mut c_double,
+        height: *mut c_double,
+    ) -> c_int,
+}
+
+impl CachedSymbols {
+    /// Loads `library_path` and resolves the cached symbol set from it.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `library_path` points at a genuine Pdfium
+    /// dynamic library matching the ABI these symbols are declared with.
+    pub(crate) unsafe fn load(library_path: &Path) -> Result<Self, libloading::Error> {
+        let library = Library::new(library_path)?;
+        let fpdf_get_page_count = {
+            let sym: Symbol<unsafe extern "C" fn(*mut std::ffi::c_void) -> c_int> =
+                library.get(b"FPDF_GetPageCount\0")?;
+            std::mem::transmute(*sym)
+        };
+        let fpdf_get_page_size_by_index_f = {
+            let sym: Symbol<
+                unsafe extern "C" fn(
+                    *mut std::ffi::c_void,
+                    c_int,
+                    *mut c_double,
+                    *mut c_double,
+                ) -> c_int,
+            > = library.get(b"FPDF_GetPageSizeByIndexF\0")?;
+            std::mem::transmute(*sym)
+        };
+        Ok(Self {
+            _library: library,
+            fpdf_get_page_count,
+            fpdf_get_page_size_by_index_f,
+        })
+    }
+}
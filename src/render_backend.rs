@@ -0,0 +1,180 @@
+use crate::pdfium_config::{init_pdfium, PdfiumConfig};
+use crate::settings::TfSettings;
+use crate::tables::find_tables;
+use std::process::Command;
+use std::rc::Rc;
+
+/// A single detected table's cell text, flattened in row-major order, as
+/// reported by one rendering backend for one page.
+pub type BackendTable = Vec<Vec<String>>;
+
+/// An abstraction over "a thing that can extract tables from a PDF page",
+/// so results from Pdfium can be cross-checked against an alternative
+/// renderer to catch cases where undefined or malformed PDF content is
+/// interpreted differently by different viewers.
+pub trait RenderBackend {
+    /// A short, stable name for this backend, used in diff reports.
+    fn name(&self) -> &str;
+
+    /// Extracts tables (as flattened cell-text grids) from the given page of
+    /// `path`.
+    fn extract_tables(&self, path: &str, page_idx: usize) -> Result<Vec<BackendTable>, String>;
+}
+
+/// The crate's native Pdfium-backed extraction path, wrapped as a
+/// [`RenderBackend`] so it can participate in cross-renderer comparisons.
+pub struct PdfiumBackend {
+    settings: Rc<TfSettings>,
+}
+
+impl PdfiumBackend {
+    /// Creates a backend using the given table-finder settings.
+    pub fn new(settings: Rc<TfSettings>) -> Self {
+        Self { settings }
+    }
+}
+
+impl RenderBackend for PdfiumBackend {
+    fn name(&self) -> &str {
+        "pdfium"
+    }
+
+    fn extract_tables(&self, path: &str, page_idx: usize) -> Result<Vec<BackendTable>, String> {
+        let pdfium = init_pdfium(&PdfiumConfig::default()).map_err(|e| format!("{:?}", e))?;
+        let document = pdfium
+            .load_pdf_from_file(path, None)
+            .map_err(|e| format!("{:?}", e))?;
+        let page = document
+            .pages()
+            .get(page_idx as _)
+            .map_err(|e| format!("{:?}", e))?;
+        let page = crate::pages::Page::new(unsafe { std::mem::transmute(page) }, page_idx);
+        let tables = find_tables(&page, self.settings.clone(), true);
+        Ok(tables
+            .iter()
+            .map(|t| t.rows().iter().map(|row| row_text(row)).collect())
+            .collect())
+    }
+}
+
+fn row_text(row: &crate::tables::CellGroup<'_>) -> Vec<String> {
+    row.cells
+        .iter()
+        .map(|c| c.map(|c| c.text.clone()).unwrap_or_default())
+        .collect()
+}
+
+/// An external-renderer backend that shells out to a subprocess (e.g. a
+/// `pdf.js`-based or Quartz-based extractor script) and parses its stdout as
+/// one line per row, tab-separated cell text, blank lines separating tables.
+pub struct SubprocessBackend {
+    name: String,
+    command: String,
+}
+
+impl SubprocessBackend {
+    /// Creates a backend invoking `command path page_idx` and parsing its
+    /// stdout as described above.
+    pub fn new(name: impl Into<String>, command: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            command: command.into(),
+        }
+    }
+}
+
+impl RenderBackend for SubprocessBackend {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn extract_tables(&self, path: &str, page_idx: usize) -> Result<Vec<BackendTable>, String> {
+        let output = Command::new(&self.command)
+            .arg(path)
+            .arg(page_idx.to_string())
+            .output()
+            .map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(format!(
+                "{} exited with {:?}",
+                self.command, output.status
+            ));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut tables = Vec::new();
+        let mut current: BackendTable = Vec::new();
+        for line in stdout.lines() {
+            if line.is_empty() {
+                if !current.is_empty() {
+                    tables.push(std::mem::take(&mut current));
+                }
+            } else {
+                current.push(line.split('\t').map(str::to_string).collect());
+            }
+        }
+        if !current.is_empty() {
+            tables.push(current);
+        }
+        Ok(tables)
+    }
+}
+
+/// A discrepancy found between two backends' extraction of the same page.
+#[derive(Debug, Clone)]
+pub struct BackendDiff {
+    /// The page index the discrepancy was found on.
+    pub page_idx: usize,
+    /// The name of the reference backend.
+    pub reference: String,
+    /// The name of the backend being compared against the reference.
+    pub candidate: String,
+    /// A human-readable description of the discrepancy.
+    pub description: String,
+}
+
+/// Extracts tables from `path`/`page_idx` through every backend and reports
+/// any discrepancy against the first backend (treated as the reference).
+///
+/// Returns an empty vector when all backends agree.
+pub fn cross_validate(
+    backends: &[Box<dyn RenderBackend>],
+    path: &str,
+    page_idx: usize,
+) -> Result<Vec<BackendDiff>, String> {
+    let (reference, rest) = match backends.split_first() {
+        Some(split) => split,
+        None => return Ok(Vec::new()),
+    };
+    let reference_tables = reference.extract_tables(path, page_idx)?;
+
+    let mut diffs = Vec::new();
+    for backend in rest {
+        let candidate_tables = backend.extract_tables(path, page_idx)?;
+        if candidate_tables.len() != reference_tables.len() {
+            diffs.push(BackendDiff {
+                page_idx,
+                reference: reference.name().to_string(),
+                candidate: backend.name().to_string(),
+                description: format!(
+                    "table count differs: {} found {}, {} found {}",
+                    reference.name(),
+                    reference_tables.len(),
+                    backend.name(),
+                    candidate_tables.len()
+                ),
+            });
+            continue;
+        }
+        for (i, (r, c)) in reference_tables.iter().zip(&candidate_tables).enumerate() {
+            if r != c {
+                diffs.push(BackendDiff {
+                    page_idx,
+                    reference: reference.name().to_string(),
+                    candidate: backend.name().to_string(),
+                    description: format!("table #{i} cell contents differ"),
+                });
+            }
+        }
+    }
+    Ok(diffs)
+}
@@ -0,0 +1,158 @@
+use crate::objects::BboxKey;
+use crate::pages::Page;
+use crate::settings::TfSettings;
+use crate::tables::{cells_to_tables, find_all_cells_bboxes, TableFinder};
+use pdfium_render::prelude::PdfRenderConfig;
+use std::rc::Rc;
+
+/// RGBA color for the horizontal/vertical edge overlay (orange).
+const EDGE_COLOR: [u8; 4] = [255, 140, 0, 255];
+/// RGBA color for the detected-cell overlay (cyan).
+const CELL_COLOR: [u8; 4] = [0, 200, 255, 255];
+/// RGBA color for the detected-table boundary overlay (magenta).
+const TABLE_COLOR: [u8; 4] = [255, 0, 200, 255];
+
+/// Which overlay layers [`render_debug`] should draw.
+#[derive(Debug, Clone, Copy)]
+pub struct DebugLayers {
+    /// Draw the horizontal/vertical edges Pdfium's `make_edges` detected.
+    pub edges: bool,
+    /// Draw the bounding box of every detected cell.
+    pub cells: bool,
+    /// Draw the bounding box of every detected table (the union of its cells).
+    pub tables: bool,
+}
+
+impl Default for DebugLayers {
+    fn default() -> Self {
+        Self {
+            edges: true,
+            cells: true,
+            tables: true,
+        }
+    }
+}
+
+/// Rasterizes `page` at `scale` and draws the requested debug overlays on top,
+/// returning the raw RGBA8 pixel buffer along with its width/height.
+///
+/// This mirrors exactly what `find_tables`/`get_edges` would compute for
+/// `tf_settings`, so the overlay reflects the settings under test rather than
+/// a separate debug-only code path.
+pub fn render_debug(
+    page: &Page,
+    tf_settings: Rc<TfSettings>,
+    scale: f32,
+    layers: DebugLayers,
+) -> Result<(Vec<u8>, u32, u32), String> {
+    let render_config = PdfRenderConfig::new().scale_page_by_factor(scale);
+    let bitmap = page
+        .inner
+        .render_with_config(&render_config)
+        .map_err(|e| format!("{:?}", e))?;
+
+    let width = bitmap.width() as u32;
+    let height = bitmap.height() as u32;
+    let mut buffer = bitmap.as_rgba_bytes();
+    let page_height = page.height();
+
+    let table_finder = TableFinder::new(tf_settings.clone());
+
+    if layers.edges {
+        let found_edges = table_finder.get_edges(page);
+        for edges in found_edges.values() {
+            for edge in edges {
+                let (x1, y1) = scale_point(edge.x1.into_inner(), edge.y1.into_inner(), scale);
+                let (x2, y2) = scale_point(edge.x2.into_inner(), edge.y2.into_inner(), scale);
+                draw_line(&mut buffer, width, height, x1, y1, x2, y2, EDGE_COLOR);
+            }
+        }
+    }
+
+    if layers.cells || layers.tables {
+        let cells = find_all_cells_bboxes(page, tf_settings.clone());
+
+        if layers.cells {
+            for cell in &cells {
+                draw_bbox_rect(&mut buffer, width, height, cell, scale, CELL_COLOR);
+            }
+        }
+
+        if layers.tables {
+            for table_cells in cells_to_tables(&cells) {
+                if let Some(bbox) = union_bbox(&table_cells) {
+                    draw_bbox_rect(&mut buffer, width, height, &bbox, scale, TABLE_COLOR);
+                }
+            }
+        }
+    }
+
+    Ok((buffer, width, height))
+}
+
+/// Returns the bounding box covering every bbox in `bboxes`, or `None` if empty.
+fn union_bbox(bboxes: &[BboxKey]) -> Option<BboxKey> {
+    bboxes.iter().copied().fold(None, |acc, (x1, y1, x2, y2)| {
+        Some(match acc {
+            None => (x1, y1, x2, y2),
+            Some((ax1, ay1, ax2, ay2)) => (ax1.min(x1), ay1.min(y1), ax2.max(x2), ay2.max(y2)),
+        })
+    })
+}
+
+/// Converts a page-space point (origin top-left, y-down, in points) into a
+/// raster-space pixel coordinate (origin top-left, y-down) at `scale`.
+fn scale_point(x: f32, y: f32, scale: f32) -> (i64, i64) {
+    ((x * scale).round() as i64, (y * scale).round() as i64)
+}
+
+fn draw_bbox_rect(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    bbox: &BboxKey,
+    scale: f32,
+    color: [u8; 4],
+) {
+    let (x1, y1) = scale_point(bbox.0.into_inner(), bbox.1.into_inner(), scale);
+    let (x2, y2) = scale_point(bbox.2.into_inner(), bbox.3.into_inner(), scale);
+    draw_line(buffer, width, height, x1, y1, x2, y1, color);
+    draw_line(buffer, width, height, x1, y2, x2, y2, color);
+    draw_line(buffer, width, height, x1, y1, x1, y2, color);
+    draw_line(buffer, width, height, x2, y1, x2, y2, color);
+}
+
+fn set_pixel(buffer: &mut [u8], width: u32, height: u32, x: i64, y: i64, color: [u8; 4]) {
+    if x < 0 || y < 0 || x as u32 >= width || y as u32 >= height {
+        return;
+    }
+    let idx = ((y as u32 * width + x as u32) * 4) as usize;
+    buffer[idx..idx + 4].copy_from_slice(&color);
+}
+
+/// Draws a straight line between two raster-space points (Bresenham's
+/// algorithm), clipping pixels that fall outside the buffer.
+fn draw_line(buffer: &mut [u8], width: u32, height: u32, x1: i64, y1: i64, x2: i64, y2: i64, color: [u8; 4]) {
+    let (mut x0, mut y0) = (x1, y1);
+    let dx = (x2 - x1).abs();
+    let sx: i64 = if x1 < x2 { 1 } else { -1 };
+    let dy = -(y2 - y1).abs();
+    let sy: i64 = if y1 < y2 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    loop {
+        set_pixel(buffer, width, height, x0, y0, color);
+        if x0 == x2 && y0 == y2 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x0 += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y0 += sy;
+        }
+    }
+}
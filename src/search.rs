@@ -0,0 +1,251 @@
+use crate::objects::BboxKey;
+use crate::words::{fold_for_search, Word};
+
+/// A word matched by [`WordSearch::find`], alongside its edit distance from
+/// the query.
+#[derive(Debug)]
+pub(crate) struct WordMatch<'w> {
+    /// The matched word.
+    pub word: &'w Word,
+    /// The edit distance between the (folded) query and the matched text,
+    /// or, in prefix mode, the best distance against any prefix of it.
+    pub distance: usize,
+}
+
+/// Typo-tolerant lookup over the output of [`crate::words::WordExtractor`].
+///
+/// Matches are found with a bounded Levenshtein (optionally
+/// Damerau-Levenshtein, i.e. treating an adjacent-character transposition
+/// as a single edit) dynamic-programming comparison, case- and
+/// ligature-folded via [`fold_for_search`] so "ﬁle" matches "file". This
+/// makes the extracted text layer queryable for locating labels or headers
+/// by approximate match, which is valuable when OCR or font encoding
+/// introduces small errors.
+pub(crate) struct WordSearch<'w> {
+    words: &'w [Word],
+}
+
+impl<'w> WordSearch<'w> {
+    /// Creates a search index over `words` (typically the output of
+    /// [`crate::words::WordExtractor::extract_words`]).
+    pub(crate) fn new(words: &'w [Word]) -> Self {
+        Self { words }
+    }
+
+    /// Finds words within `max_distance` edits of `query`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The term to search for.
+    /// * `max_distance` - The maximum allowed edit distance (practically
+    ///   0, 1, or 2; larger budgets cost proportionally more to search).
+    /// * `allow_transposition` - When `true`, swapping two adjacent
+    ///   characters counts as a single edit instead of two.
+    /// * `prefix` - When `true`, a word matches if any prefix of it is
+    ///   within `max_distance` of `query`, rather than requiring the whole
+    ///   word to match.
+    ///
+    /// # Returns
+    ///
+    /// Matching words with their bboxes, in extraction order.
+    pub(crate) fn find(
+        &self,
+        query: &str,
+        max_distance: usize,
+        allow_transposition: bool,
+        prefix: bool,
+    ) -> Vec<WordMatch<'w>> {
+        let query_folded: Vec<char> = fold_for_search(query).chars().collect();
+
+        self.words
+            .iter()
+            .filter_map(|word| {
+                let word_folded: Vec<char> = fold_for_search(&word.text).chars().collect();
+                bounded_edit_distance(
+                    &query_folded,
+                    &word_folded,
+                    max_distance,
+                    allow_transposition,
+                    prefix,
+                )
+                .map(|distance| WordMatch { word, distance })
+            })
+            .collect()
+    }
+}
+
+/// Runs a typo-tolerant search for `query` over `words`, returning each
+/// match's text, bbox, and edit distance, in extraction order. This is the
+/// entry point the crate's `search_text` Python function calls to make the
+/// text layer queryable.
+pub(crate) fn search_words(
+    words: &[Word],
+    query: &str,
+    max_distance: usize,
+    allow_transposition: bool,
+    prefix: bool,
+) -> Vec<(String, BboxKey, usize)> {
+    WordSearch::new(words)
+        .find(query, max_distance, allow_transposition, prefix)
+        .into_iter()
+        .map(|m| (m.word.text.clone(), m.word.bbox, m.distance))
+        .collect()
+}
+
+/// Computes the edit distance between `query` and `candidate` (or, in
+/// prefix mode, between `query` and the best-matching prefix of
+/// `candidate`), or `None` once it is certain the distance exceeds
+/// `max_distance`.
+///
+/// Uses row-by-row dynamic programming, aborting as soon as the minimum
+/// value in a row exceeds `max_distance`: every later row's minimum can
+/// only be at least as large, so the query can never come back within
+/// budget.
+fn bounded_edit_distance(
+    query: &[char],
+    candidate: &[char],
+    max_distance: usize,
+    allow_transposition: bool,
+    prefix: bool,
+) -> Option<usize> {
+    let n = query.len();
+    let m = candidate.len();
+
+    let mut prev_prev_row: Vec<usize> = vec![usize::MAX; m + 1];
+    let mut prev_row: Vec<usize> = (0..=m).collect();
+
+    if n == 0 {
+        let distance = if prefix { 0 } else { m };
+        return (distance <= max_distance).then_some(distance);
+    }
+
+    for i in 1..=n {
+        let mut curr_row = vec![0usize; m + 1];
+        curr_row[0] = i;
+        let mut row_min = curr_row[0];
+
+        for j in 1..=m {
+            let cost = usize::from(query[i - 1] != candidate[j - 1]);
+            let mut value = (prev_row[j] + 1)
+                .min(curr_row[j - 1] + 1)
+                .min(prev_row[j - 1] + cost);
+
+            if allow_transposition
+                && i > 1
+                && j > 1
+                && query[i - 1] == candidate[j - 2]
+                && query[i - 2] == candidate[j - 1]
+            {
+                value = value.min(prev_prev_row[j - 2] + 1);
+            }
+
+            curr_row[j] = value;
+            row_min = row_min.min(value);
+        }
+
+        if row_min > max_distance {
+            return None;
+        }
+
+        prev_prev_row = prev_row;
+        prev_row = curr_row;
+    }
+
+    let distance = if prefix {
+        *prev_row.iter().min().unwrap()
+    } else {
+        prev_row[m]
+    };
+
+    (distance <= max_distance).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ordered_float::OrderedFloat;
+
+    fn word(text: &str) -> Word {
+        Word {
+            text: text.to_string(),
+            bbox: bbox(),
+            rotation_degrees: OrderedFloat(0.0),
+            extra_attrs: Vec::new(),
+        }
+    }
+
+    fn bbox() -> BboxKey {
+        (
+            OrderedFloat(0.0),
+            OrderedFloat(0.0),
+            OrderedFloat(1.0),
+            OrderedFloat(1.0),
+        )
+    }
+
+    #[test]
+    fn test_exact_match() {
+        let words = vec![word("Invoice"), word("Total")];
+        let search = WordSearch::new(&words);
+        let matches = search.find("invoice", 0, false, false);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].word.text, "Invoice");
+        assert_eq!(matches[0].distance, 0);
+    }
+
+    #[test]
+    fn test_single_edit_typo() {
+        let words = vec![word("Invoice")];
+        let search = WordSearch::new(&words);
+        // One substitution away.
+        let matches = search.find("Invoixe", 1, false, false);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].distance, 1);
+
+        // Too far away at distance budget 0.
+        assert!(search.find("Invoixe", 0, false, false).is_empty());
+    }
+
+    #[test]
+    fn test_transposition_counts_as_one_edit() {
+        let words = vec![word("Total")];
+        let search = WordSearch::new(&words);
+
+        // "Totla" is "Total" with the last two letters swapped.
+        assert!(search.find("Totla", 1, false, false).is_empty());
+        let matches = search.find("Totla", 1, true, false);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].distance, 1);
+    }
+
+    #[test]
+    fn test_prefix_mode_matches_truncated_word() {
+        let words = vec![word("Subtotal")];
+        let search = WordSearch::new(&words);
+
+        assert!(search.find("Sub", 0, false, false).is_empty());
+        let matches = search.find("Sub", 0, false, true);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].distance, 0);
+    }
+
+    #[test]
+    fn test_ligature_folding() {
+        let words = vec![word("\u{FB01}le")];
+        let search = WordSearch::new(&words);
+        let matches = search.find("file", 0, false, false);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].distance, 0);
+    }
+
+    #[test]
+    fn test_search_words_returns_owned_text_bbox_and_distance() {
+        let words = vec![word("Invoice"), word("Total")];
+        let matches = search_words(&words, "invoice", 0, false, false);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, "Invoice");
+        assert_eq!(matches[0].1, bbox());
+        assert_eq!(matches[0].2, 0);
+    }
+}
@@ -1,9 +1,11 @@
+use crate::edges::Edge;
 use ordered_float::OrderedFloat;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
 use std::fmt;
 use std::ops::{BitAnd, Deref};
+use std::str::FromStr;
 use thiserror::Error;
 
 /// Error type for invalid non-negative float values.
@@ -29,6 +31,60 @@ impl From<NegativeValueError> for PyErr {
     }
 }
 
+/// Error type for non-finite (NaN or infinite) float values.
+#[derive(Debug, Clone, Error)]
+#[error("{field_name} must be finite, got {value}")]
+pub struct NonFiniteValueError {
+    pub field_name: String,
+    pub value: f32,
+}
+
+impl NonFiniteValueError {
+    pub fn new(field_name: impl Into<String>, value: f32) -> Self {
+        Self {
+            field_name: field_name.into(),
+            value,
+        }
+    }
+}
+
+impl From<NonFiniteValueError> for PyErr {
+    fn from(err: NonFiniteValueError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Error type for an invalid `NonNegativeF32` value: either negative or
+/// non-finite.
+#[derive(Debug, Clone, Error)]
+pub enum NonNegativeF32Error {
+    #[error(transparent)]
+    Negative(#[from] NegativeValueError),
+    #[error(transparent)]
+    NonFinite(#[from] NonFiniteValueError),
+}
+
+impl From<NonNegativeF32Error> for PyErr {
+    fn from(err: NonNegativeF32Error) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Error type for an unrecognized strategy string.
+#[derive(Debug, Clone, Error)]
+#[error(
+    "invalid strategy {got:?}, expected one of \"lines\", \"lines_strict\", \"text\", \"explicit\", \"projection\""
+)]
+pub struct InvalidStrategyError {
+    pub got: String,
+}
+
+impl From<InvalidStrategyError> for PyErr {
+    fn from(err: InvalidStrategyError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
 /// A non-negative floating point number wrapper.
 ///
 /// This type ensures that the wrapped value is always >= 0.0.
@@ -46,10 +102,15 @@ impl NonNegativeF32 {
     ///
     /// # Returns
     ///
-    /// Ok(NonNegativeF32) if value >= 0, Err(NegativeValueError) otherwise.
-    pub fn new(value: f32, field_name: impl Into<String>) -> Result<Self, NegativeValueError> {
-        if value < 0.0 {
-            Err(NegativeValueError::new(field_name, value))
+    /// Ok(NonNegativeF32) if value is finite and >= 0, Err otherwise
+    /// (Err(NonFiniteValueError) for NaN/infinite, Err(NegativeValueError)
+    /// for a finite negative value).
+    pub fn new(value: f32, field_name: impl Into<String>) -> Result<Self, NonNegativeF32Error> {
+        let field_name = field_name.into();
+        if !value.is_finite() {
+            Err(NonFiniteValueError::new(field_name, value).into())
+        } else if value < 0.0 {
+            Err(NegativeValueError::new(field_name, value).into())
         } else {
             Ok(Self(OrderedFloat::from(value)))
         }
@@ -104,6 +165,146 @@ impl Deref for NonNegativeF32 {
     }
 }
 
+impl TryFrom<f32> for NonNegativeF32 {
+    type Error = NonNegativeF32Error;
+
+    /// Validates `value` as non-negative under a generic "value" field
+    /// name. Prefer `NonNegativeF32::new` when a field name is at hand, for
+    /// a clearer error message.
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::new(value, "value")
+    }
+}
+
+/// Error for a finite value outside its declared `[min, max]` interval.
+#[derive(Debug, Clone, Error)]
+#[error("{field_name} must be between {min} and {max}, got {value}")]
+pub struct OutOfRangeError {
+    pub field_name: String,
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl From<OutOfRangeError> for PyErr {
+    fn from(err: OutOfRangeError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Error type for an invalid `BoundedF32` value: non-finite or outside the
+/// declared interval.
+#[derive(Debug, Clone, Error)]
+pub enum BoundedF32Error {
+    #[error(transparent)]
+    NonFinite(#[from] NonFiniteValueError),
+    #[error(transparent)]
+    OutOfRange(#[from] OutOfRangeError),
+}
+
+impl From<BoundedF32Error> for PyErr {
+    fn from(err: BoundedF32Error) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// A floating point value validated to lie within a runtime-declared
+/// `[min, max]` interval.
+///
+/// `NonNegativeF32` is the common `[0.0, f32::INFINITY]` case; `BoundedF32`
+/// generalizes it to settings that also need an upper bound (e.g. a ratio
+/// capped at 1.0), validating through the same structured-error code path
+/// and reporting the allowed interval in the error message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundedF32 {
+    value: OrderedFloat<f32>,
+    min: f32,
+    max: f32,
+}
+
+impl BoundedF32 {
+    /// Creates a new BoundedF32, validating that `value` is finite and
+    /// falls within `[min, max]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to wrap.
+    /// * `min` - The inclusive lower bound.
+    /// * `max` - The inclusive upper bound.
+    /// * `field_name` - The name of the field (for error messages).
+    pub fn new(
+        value: f32,
+        min: f32,
+        max: f32,
+        field_name: impl Into<String>,
+    ) -> Result<Self, BoundedF32Error> {
+        let field_name = field_name.into();
+        if !value.is_finite() {
+            Err(NonFiniteValueError::new(field_name, value).into())
+        } else if value < min || value > max {
+            Err(OutOfRangeError {
+                field_name,
+                value,
+                min,
+                max,
+            }
+            .into())
+        } else {
+            Ok(Self {
+                value: OrderedFloat(value),
+                min,
+                max,
+            })
+        }
+    }
+
+    /// Creates a new BoundedF32 without validation.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `value` is finite and within `[min, max]`.
+    #[inline]
+    pub const fn new_unchecked(value: f32, min: f32, max: f32) -> Self {
+        Self {
+            value: OrderedFloat(value),
+            min,
+            max,
+        }
+    }
+
+    /// Returns the inner f32 value.
+    #[inline]
+    pub fn into_inner(self) -> f32 {
+        self.value.into_inner()
+    }
+
+    /// Returns the interval's inclusive lower bound.
+    #[inline]
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns the interval's inclusive upper bound.
+    #[inline]
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+}
+
+impl fmt::Display for BoundedF32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Deref for BoundedF32 {
+    type Target = OrderedFloat<f32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
 /// Default tolerance for snapping nearby edges together.
 static DEFAULT_SNAP_TOLERANCE: f32 = 3.0;
 /// Default tolerance for joining overlapping edges.
@@ -118,6 +319,21 @@ static DEFAULT_MIN_WORDS_HORIZONTAL: usize = 1;
 static DEFAULT_X_TOLERANCE: f32 = 3.0;
 /// Default y-tolerance for word extraction.
 static DEFAULT_Y_TOLERANCE: f32 = 3.0;
+/// Default minimum fraction of a character's own area that must fall
+/// inside a cell for the character to be assigned to it.
+static DEFAULT_CELL_OVERLAP_RATIO: f32 = 0.5;
+/// Default minimum width of a whitespace gutter for the projection strategy.
+static DEFAULT_MIN_GUTTER_WIDTH: f32 = 3.0;
+/// Default maximum word coverage (in bin count) allowed inside a gutter for
+/// the projection strategy; 0 requires the gutter to be entirely empty.
+static DEFAULT_GUTTER_COVERAGE_THRESHOLD: usize = 0;
+/// Default maximum gap allowed between consecutive segments of a dashed or
+/// dotted rule for them to be stitched together; 0 disables stitching so
+/// solid-line behavior is unchanged out of the box.
+static DEFAULT_DASH_MAX_GAP: f32 = 0.0;
+/// Default minimum number of short collinear segments required before a
+/// run is treated as a dashed or dotted rule.
+static DEFAULT_MIN_DASH_COUNT: usize = 4;
 
 /// Strategy types for edge detection in table finding.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -129,6 +345,41 @@ pub enum StrategyType {
     LinesStrict = 2,
     /// Infer edges from text alignment.
     Text = 4,
+    /// Force edges at user-supplied coordinates.
+    Explicit = 8,
+    /// Infer edges from whitespace gutters via a density projection,
+    /// independent of word alignment.
+    Projection = 16,
+}
+
+impl FromStr for StrategyType {
+    type Err = InvalidStrategyError;
+
+    /// Parses a strategy string ("lines", "lines_strict", "text", "explicit",
+    /// or "projection").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lines" => Ok(StrategyType::Lines),
+            "lines_strict" => Ok(StrategyType::LinesStrict),
+            "text" => Ok(StrategyType::Text),
+            "explicit" => Ok(StrategyType::Explicit),
+            "projection" => Ok(StrategyType::Projection),
+            _ => Err(InvalidStrategyError { got: s.to_string() }),
+        }
+    }
+}
+
+/// A single explicit edge supplied by the caller to augment detected edges.
+///
+/// A `Coordinate` spans the full clipped (or page) extent at the given
+/// x (for vertical lines) or y (for horizontal lines) position. A `Full`
+/// edge lets the caller pin down the exact endpoints instead.
+#[derive(Debug, Clone)]
+pub enum ExplicitLine {
+    /// A bare coordinate; the edge is stretched to span the clip region.
+    Coordinate(NonNegativeF32),
+    /// A fully specified edge, used as-is.
+    Full(Edge),
 }
 
 impl BitAnd<u8> for StrategyType {
@@ -147,6 +398,72 @@ impl BitAnd<StrategyType> for StrategyType {
     }
 }
 
+/// All known `StrategyType` variants, used to enumerate a `StrategySet`.
+const ALL_STRATEGIES: [StrategyType; 5] = [
+    StrategyType::Lines,
+    StrategyType::LinesStrict,
+    StrategyType::Text,
+    StrategyType::Explicit,
+    StrategyType::Projection,
+];
+
+/// A bitmask of `StrategyType` variants, allowing multiple edge-detection
+/// strategies to be active on the same axis at once (e.g. ruling lines
+/// combined with text-alignment inference).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StrategySet(u8);
+
+impl StrategySet {
+    /// A set with no strategies active.
+    pub const EMPTY: StrategySet = StrategySet(0);
+
+    /// Returns whether `strategy` is active in this set.
+    pub fn contains(self, strategy: StrategyType) -> bool {
+        self.0 & (strategy as u8) != 0
+    }
+
+    /// Activates `strategy` in this set.
+    pub fn insert(&mut self, strategy: StrategyType) {
+        self.0 |= strategy as u8;
+    }
+}
+
+impl From<StrategyType> for StrategySet {
+    fn from(strategy: StrategyType) -> Self {
+        StrategySet(strategy as u8)
+    }
+}
+
+impl Iterator for StrategySet {
+    type Item = StrategyType;
+
+    /// Yields each active strategy in `ALL_STRATEGIES` order, removing it
+    /// from the set as it is yielded.
+    fn next(&mut self) -> Option<Self::Item> {
+        for strategy in ALL_STRATEGIES {
+            if self.contains(strategy) {
+                self.0 &= !(strategy as u8);
+                return Some(strategy);
+            }
+        }
+        None
+    }
+}
+
+impl FromStr for StrategySet {
+    type Err = InvalidStrategyError;
+
+    /// Parses either a single strategy ("lines") or a `+`-joined combined
+    /// spec ("lines+text").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = StrategySet::EMPTY;
+        for part in s.split('+') {
+            set.insert(part.parse::<StrategyType>()?);
+        }
+        Ok(set)
+    }
+}
+
 /// Settings for table finding operations.
 ///
 /// Controls how edges are detected, snapped, joined, and how intersections
@@ -154,10 +471,12 @@ impl BitAnd<StrategyType> for StrategyType {
 #[derive(Debug, Clone)]
 #[pyclass]
 pub struct TfSettings {
-    /// Strategy for detecting vertical edges.
-    pub vertical_strategy: StrategyType,
-    /// Strategy for detecting horizontal edges.
-    pub horizontal_strategy: StrategyType,
+    /// Strategies for detecting vertical edges (may combine multiple, e.g.
+    /// ruling lines and text alignment).
+    pub vertical_strategy: StrategySet,
+    /// Strategies for detecting horizontal edges (may combine multiple, e.g.
+    /// ruling lines and text alignment).
+    pub horizontal_strategy: StrategySet,
     /// Tolerance for snapping vertical edges together.
     pub snap_x_tolerance: NonNegativeF32,
     /// Tolerance for snapping horizontal edges together.
@@ -178,15 +497,40 @@ pub struct TfSettings {
     pub intersection_x_tolerance: NonNegativeF32,
     /// Y-tolerance for detecting edge intersections.
     pub intersection_y_tolerance: NonNegativeF32,
+    /// Minimum width of a whitespace gutter for the projection strategy to
+    /// emit an edge through its center.
+    pub min_gutter_width: NonNegativeF32,
+    /// Maximum word coverage (in bin count) a gutter may contain and still
+    /// count as whitespace for the projection strategy; 0 requires the
+    /// gutter to be entirely empty.
+    pub gutter_coverage_threshold: usize,
+    /// Maximum gap allowed between consecutive segments of a dashed or
+    /// dotted rule for them to be stitched into one edge; 0 disables
+    /// stitching.
+    pub dash_max_gap: NonNegativeF32,
+    /// Minimum number of short collinear segments required before a run is
+    /// treated as a dashed or dotted rule and stitched together.
+    pub min_dash_count: usize,
+    /// When `true`, edges are only snapped and joined against others of a
+    /// similar color and width, so a thin light gridline isn't averaged
+    /// into a thick dark border that happens to sit nearby.
+    pub respect_edge_style: bool,
     /// Settings for text/word extraction.
     pub text_settings: WordsExtractSettings,
+    /// User-supplied vertical lines injected into the edge set before merging.
+    pub explicit_vertical_lines: Vec<ExplicitLine>,
+    /// User-supplied horizontal lines injected into the edge set before merging.
+    pub explicit_horizontal_lines: Vec<ExplicitLine>,
+    /// Optional bounding box restricting edge extraction and text assignment
+    /// to a sub-region of the page, as (x1, y1, x2, y2).
+    pub clip_bbox: Option<(f32, f32, f32, f32)>,
 }
 impl Default for TfSettings {
     /// Creates a TfSettings instance with default values.
     fn default() -> Self {
         TfSettings {
-            vertical_strategy: StrategyType::LinesStrict, // LinesStrict is more intuitive for default behavior
-            horizontal_strategy: StrategyType::LinesStrict,
+            vertical_strategy: StrategySet::from(StrategyType::LinesStrict), // LinesStrict is more intuitive for default behavior
+            horizontal_strategy: StrategySet::from(StrategyType::LinesStrict),
             snap_x_tolerance: NonNegativeF32::new_unchecked(DEFAULT_SNAP_TOLERANCE),
             snap_y_tolerance: NonNegativeF32::new_unchecked(DEFAULT_SNAP_TOLERANCE),
             join_x_tolerance: NonNegativeF32::new_unchecked(DEFAULT_JOIN_TOLERANCE),
@@ -197,50 +541,140 @@ impl Default for TfSettings {
             min_words_horizontal: DEFAULT_MIN_WORDS_HORIZONTAL,
             intersection_x_tolerance: NonNegativeF32::new_unchecked(DEFAULT_INTERSECTION_TOLERANCE),
             intersection_y_tolerance: NonNegativeF32::new_unchecked(DEFAULT_INTERSECTION_TOLERANCE),
+            min_gutter_width: NonNegativeF32::new_unchecked(DEFAULT_MIN_GUTTER_WIDTH),
+            gutter_coverage_threshold: DEFAULT_GUTTER_COVERAGE_THRESHOLD,
+            dash_max_gap: NonNegativeF32::new_unchecked(DEFAULT_DASH_MAX_GAP),
+            min_dash_count: DEFAULT_MIN_DASH_COUNT,
+            respect_edge_style: false,
             text_settings: WordsExtractSettings::default(),
+            explicit_vertical_lines: Vec::new(),
+            explicit_horizontal_lines: Vec::new(),
+            clip_bbox: None,
         }
     }
 }
 
 /// Helper methods for strategy conversion (not exposed to Python).
 impl TfSettings {
-    /// Converts a strategy string to its enum representation.
+    /// Converts a StrategyType enum to its string representation.
     ///
     /// # Arguments
     ///
-    /// * `strategy_str` - The strategy name ("lines", "lines_strict", or "text").
+    /// * `strategy` - The strategy enum value.
     ///
     /// # Returns
     ///
-    /// The corresponding StrategyType enum value.
+    /// The string name of the strategy.
+    fn strategy_enum_to_str(strategy: StrategyType) -> &'static str {
+        match strategy {
+            StrategyType::Lines => "lines",
+            StrategyType::LinesStrict => "lines_strict",
+            StrategyType::Text => "text",
+            StrategyType::Explicit => "explicit",
+            StrategyType::Projection => "projection",
+        }
+    }
+
+    /// Converts a `StrategySet` to its canonical combined string
+    /// representation (e.g. "lines+text").
+    ///
+    /// # Arguments
+    ///
+    /// * `strategies` - The set of active strategies.
+    ///
+    /// # Returns
+    ///
+    /// The `+`-joined names of the active strategies.
+    fn strategy_set_to_str(strategies: StrategySet) -> String {
+        strategies
+            .map(Self::strategy_enum_to_str)
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+
+    /// Extracts a `StrategySet` from a Python value.
+    ///
+    /// Accepts either a single strategy string ("lines"), a combined
+    /// `+`-joined string ("lines+text"), or a list of strategy strings
+    /// (`["lines", "text"]`).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The Python value to parse.
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// Panics if an invalid strategy string is provided.
-    fn strategy_str_to_enum(strategy_str: &str) -> StrategyType {
-        match strategy_str {
-            "lines" => StrategyType::Lines,
-            "lines_strict" => StrategyType::LinesStrict,
-            "text" => StrategyType::Text,
-            _ => panic!("Invalid strategy: {}", strategy_str),
+    /// The parsed `StrategySet`, or a `PyValueError` if a name is unrecognized.
+    fn extract_strategy_set(value: &Bound<'_, PyAny>) -> PyResult<StrategySet> {
+        if let Ok(s) = value.extract::<String>() {
+            Ok(s.parse()?)
+        } else {
+            let names = value.extract::<Vec<String>>()?;
+            let mut set = StrategySet::EMPTY;
+            for name in names {
+                set.insert(name.parse::<StrategyType>()?);
+            }
+            Ok(set)
         }
     }
 
-    /// Converts a StrategyType enum to its string representation.
+    /// Extracts a list of explicit lines from a Python value.
+    ///
+    /// Each item may be a bare coordinate (float) or a full `Edge` object.
     ///
     /// # Arguments
     ///
-    /// * `strategy` - The strategy enum value.
+    /// * `value` - The Python list of coordinates and/or `Edge` objects.
+    /// * `field_name` - The name of the field (for error messages).
     ///
     /// # Returns
     ///
-    /// The string name of the strategy.
-    fn strategy_enum_to_str(strategy: StrategyType) -> &'static str {
-        match strategy {
-            StrategyType::Lines => "lines",
-            StrategyType::LinesStrict => "lines_strict",
-            StrategyType::Text => "text",
+    /// The parsed list of `ExplicitLine`s, or a `PyValueError` if an item is
+    /// neither a number nor an `Edge`, or a bare coordinate is negative or
+    /// non-finite.
+    fn extract_explicit_lines(
+        value: &Bound<'_, PyAny>,
+        field_name: &str,
+    ) -> PyResult<Vec<ExplicitLine>> {
+        let items = value
+            .downcast::<PyList>()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        items
+            .iter()
+            .map(|item| {
+                if let Ok(coord) = item.extract::<f32>() {
+                    Ok(ExplicitLine::Coordinate(NonNegativeF32::new(
+                        coord, field_name,
+                    )?))
+                } else {
+                    item.extract::<Edge>().map(ExplicitLine::Full)
+                }
+            })
+            .collect()
+    }
+
+    /// Converts a list of explicit lines into a Python list.
+    ///
+    /// # Arguments
+    ///
+    /// * `py` - The Python GIL token.
+    /// * `lines` - The explicit lines to convert.
+    ///
+    /// # Returns
+    ///
+    /// A Python list of floats and/or `Edge` objects.
+    fn explicit_lines_to_pylist<'py>(
+        py: Python<'py>,
+        lines: &[ExplicitLine],
+    ) -> PyResult<Bound<'py, PyList>> {
+        let list = PyList::empty(py);
+        for line in lines {
+            match line {
+                ExplicitLine::Coordinate(v) => list.append(v.into_inner())?,
+                ExplicitLine::Full(edge) => list.append(edge.clone())?,
+            }
         }
+        Ok(list)
     }
 }
 
@@ -269,76 +703,102 @@ impl TfSettings {
                 let key = key.to_string();
                 match key.as_str() {
                     "vertical_strategy" => {
-                        settings.vertical_strategy =
-                            Self::strategy_str_to_enum(value.extract().unwrap())
+                        settings.vertical_strategy = Self::extract_strategy_set(&value)?
                     }
                     "horizontal_strategy" => {
-                        settings.horizontal_strategy =
-                            Self::strategy_str_to_enum(value.extract().unwrap())
+                        settings.horizontal_strategy = Self::extract_strategy_set(&value)?
                     }
                     "snap_x_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.snap_x_tolerance = NonNegativeF32::new(v, "snap_x_tolerance")?;
                     }
                     "snap_y_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.snap_y_tolerance = NonNegativeF32::new(v, "snap_y_tolerance")?;
                     }
                     "join_x_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.join_x_tolerance = NonNegativeF32::new(v, "join_x_tolerance")?;
                     }
                     "join_y_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.join_y_tolerance = NonNegativeF32::new(v, "join_y_tolerance")?;
                     }
                     "edge_min_length" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.edge_min_length = NonNegativeF32::new(v, "edge_min_length")?;
                     }
                     "edge_min_length_prefilter" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.edge_min_length_prefilter =
                             NonNegativeF32::new(v, "edge_min_length_prefilter")?;
                     }
                     "min_words_vertical" => {
-                        settings.min_words_vertical = value.extract::<usize>().unwrap()
+                        settings.min_words_vertical = value.extract::<usize>()?
                     }
                     "min_words_horizontal" => {
-                        settings.min_words_horizontal = value.extract::<usize>().unwrap()
+                        settings.min_words_horizontal = value.extract::<usize>()?
                     }
                     "intersection_x_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.intersection_x_tolerance =
                             NonNegativeF32::new(v, "intersection_x_tolerance")?;
                     }
                     "intersection_y_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.intersection_y_tolerance =
                             NonNegativeF32::new(v, "intersection_y_tolerance")?;
                     }
+                    "min_gutter_width" => {
+                        let v = value.extract::<f32>()?;
+                        settings.min_gutter_width = NonNegativeF32::new(v, "min_gutter_width")?;
+                    }
+                    "gutter_coverage_threshold" => {
+                        settings.gutter_coverage_threshold = value.extract::<usize>()?
+                    }
+                    "dash_max_gap" => {
+                        let v = value.extract::<f32>()?;
+                        settings.dash_max_gap = NonNegativeF32::new(v, "dash_max_gap")?;
+                    }
+                    "min_dash_count" => {
+                        settings.min_dash_count = value.extract::<usize>()?
+                    }
+                    "respect_edge_style" => {
+                        settings.respect_edge_style = value.extract::<bool>()?
+                    }
                     "text_x_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.text_settings.x_tolerance =
                             NonNegativeF32::new(v, "text_x_tolerance")?;
                     }
                     "text_y_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.text_settings.y_tolerance =
                             NonNegativeF32::new(v, "text_y_tolerance")?;
                     }
+                    "text_x_tolerance_ratio" => {
+                        let v: Option<f32> = value.extract()?;
+                        settings.text_settings.x_tolerance_ratio = v
+                            .map(|v| NonNegativeF32::new(v, "text_x_tolerance_ratio"))
+                            .transpose()?;
+                    }
+                    "text_y_tolerance_ratio" => {
+                        let v: Option<f32> = value.extract()?;
+                        settings.text_settings.y_tolerance_ratio = v
+                            .map(|v| NonNegativeF32::new(v, "text_y_tolerance_ratio"))
+                            .transpose()?;
+                    }
                     "text_keep_blank_chars" => {
-                        settings.text_settings.keep_blank_chars = value.extract::<bool>().unwrap()
+                        settings.text_settings.keep_blank_chars = value.extract::<bool>()?
                     }
                     "text_use_text_flow" => {
-                        settings.text_settings.use_text_flow = value.extract::<bool>().unwrap()
+                        settings.text_settings.use_text_flow = value.extract::<bool>()?
                     }
                     "text_read_in_clockwise" => {
-                        settings.text_settings.text_read_in_clockwise =
-                            value.extract::<bool>().unwrap()
+                        settings.text_settings.text_read_in_clockwise = value.extract::<bool>()?
                     }
                     "text_split_at_punctuation" => {
-                        let split_value: Option<&str> = value.extract().unwrap();
+                        let split_value: Option<&str> = value.extract()?;
                         settings.text_settings.split_at_punctuation = match split_value {
                             Some("all") => Some(SplitPunctuation::All),
                             Some(custom) => Some(SplitPunctuation::Custom(custom.to_string())),
@@ -346,7 +806,29 @@ impl TfSettings {
                         };
                     }
                     "text_expand_ligatures" => {
-                        settings.text_settings.expand_ligatures = value.extract::<bool>().unwrap()
+                        settings.text_settings.expand_ligatures = value.extract::<bool>()?
+                    }
+                    "text_normalize_unicode" => {
+                        settings.text_settings.normalize_unicode = value.extract::<bool>()?
+                    }
+                    "text_cell_overlap_ratio" => {
+                        let v = value.extract::<f32>()?;
+                        settings.text_settings.cell_overlap_ratio =
+                            BoundedF32::new(v, 0.0, 1.0, "text_cell_overlap_ratio")?;
+                    }
+                    "text_extra_attrs" => {
+                        settings.text_settings.extra_attrs = value.extract::<Vec<String>>()?;
+                    }
+                    "explicit_vertical_lines" => {
+                        settings.explicit_vertical_lines =
+                            Self::extract_explicit_lines(&value, "explicit_vertical_lines")?;
+                    }
+                    "explicit_horizontal_lines" => {
+                        settings.explicit_horizontal_lines =
+                            Self::extract_explicit_lines(&value, "explicit_horizontal_lines")?;
+                    }
+                    "clip_bbox" => {
+                        settings.clip_bbox = value.extract::<Option<(f32, f32, f32, f32)>>()?;
                     }
                     _ => (), // Ignore unknown settings
                 }
@@ -357,13 +839,13 @@ impl TfSettings {
 
     // Getters
     #[getter]
-    fn vertical_strategy(&self) -> &'static str {
-        Self::strategy_enum_to_str(self.vertical_strategy)
+    fn vertical_strategy(&self) -> String {
+        Self::strategy_set_to_str(self.vertical_strategy)
     }
 
     #[getter]
-    fn horizontal_strategy(&self) -> &'static str {
-        Self::strategy_enum_to_str(self.horizontal_strategy)
+    fn horizontal_strategy(&self) -> String {
+        Self::strategy_set_to_str(self.horizontal_strategy)
     }
 
     #[getter]
@@ -416,6 +898,31 @@ impl TfSettings {
         self.intersection_y_tolerance.into_inner()
     }
 
+    #[getter]
+    fn min_gutter_width(&self) -> f32 {
+        self.min_gutter_width.into_inner()
+    }
+
+    #[getter]
+    fn gutter_coverage_threshold(&self) -> usize {
+        self.gutter_coverage_threshold
+    }
+
+    #[getter]
+    fn dash_max_gap(&self) -> f32 {
+        self.dash_max_gap.into_inner()
+    }
+
+    #[getter]
+    fn min_dash_count(&self) -> usize {
+        self.min_dash_count
+    }
+
+    #[getter]
+    fn respect_edge_style(&self) -> bool {
+        self.respect_edge_style
+    }
+
     #[getter]
     fn text_settings(&self) -> WordsExtractSettings {
         self.text_settings.clone()
@@ -431,6 +938,16 @@ impl TfSettings {
         self.text_settings.y_tolerance.into_inner()
     }
 
+    #[getter]
+    fn text_x_tolerance_ratio(&self) -> Option<f32> {
+        self.text_settings.x_tolerance_ratio.map(|v| v.into_inner())
+    }
+
+    #[getter]
+    fn text_y_tolerance_ratio(&self) -> Option<f32> {
+        self.text_settings.y_tolerance_ratio.map(|v| v.into_inner())
+    }
+
     #[getter]
     fn text_keep_blank_chars(&self) -> bool {
         self.text_settings.keep_blank_chars
@@ -460,15 +977,47 @@ impl TfSettings {
         self.text_settings.expand_ligatures
     }
 
+    #[getter]
+    fn text_normalize_unicode(&self) -> bool {
+        self.text_settings.normalize_unicode
+    }
+
+    #[getter]
+    fn text_cell_overlap_ratio(&self) -> f32 {
+        self.text_settings.cell_overlap_ratio.into_inner()
+    }
+
+    #[getter]
+    fn text_extra_attrs(&self) -> Vec<String> {
+        self.text_settings.extra_attrs.clone()
+    }
+
+    #[getter]
+    fn explicit_vertical_lines<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        Self::explicit_lines_to_pylist(py, &self.explicit_vertical_lines)
+    }
+
+    #[getter]
+    fn explicit_horizontal_lines<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        Self::explicit_lines_to_pylist(py, &self.explicit_horizontal_lines)
+    }
+
+    #[getter]
+    fn clip_bbox(&self) -> Option<(f32, f32, f32, f32)> {
+        self.clip_bbox
+    }
+
     // Setters
     #[setter]
-    fn set_vertical_strategy(&mut self, value: &str) {
-        self.vertical_strategy = Self::strategy_str_to_enum(value);
+    fn set_vertical_strategy(&mut self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.vertical_strategy = Self::extract_strategy_set(value)?;
+        Ok(())
     }
 
     #[setter]
-    fn set_horizontal_strategy(&mut self, value: &str) {
-        self.horizontal_strategy = Self::strategy_str_to_enum(value);
+    fn set_horizontal_strategy(&mut self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.horizontal_strategy = Self::extract_strategy_set(value)?;
+        Ok(())
     }
 
     #[setter]
@@ -529,6 +1078,33 @@ impl TfSettings {
         Ok(())
     }
 
+    #[setter]
+    fn set_min_gutter_width(&mut self, value: f32) -> PyResult<()> {
+        self.min_gutter_width = NonNegativeF32::new(value, "min_gutter_width")?;
+        Ok(())
+    }
+
+    #[setter]
+    fn set_gutter_coverage_threshold(&mut self, value: usize) {
+        self.gutter_coverage_threshold = value;
+    }
+
+    #[setter]
+    fn set_dash_max_gap(&mut self, value: f32) -> PyResult<()> {
+        self.dash_max_gap = NonNegativeF32::new(value, "dash_max_gap")?;
+        Ok(())
+    }
+
+    #[setter]
+    fn set_min_dash_count(&mut self, value: usize) {
+        self.min_dash_count = value;
+    }
+
+    #[setter]
+    fn set_respect_edge_style(&mut self, value: bool) {
+        self.respect_edge_style = value;
+    }
+
     #[setter]
     fn set_text_settings(&mut self, value: WordsExtractSettings) {
         self.text_settings = value;
@@ -546,6 +1122,22 @@ impl TfSettings {
         Ok(())
     }
 
+    #[setter]
+    fn set_text_x_tolerance_ratio(&mut self, value: Option<f32>) -> PyResult<()> {
+        self.text_settings.x_tolerance_ratio = value
+            .map(|v| NonNegativeF32::new(v, "text_x_tolerance_ratio"))
+            .transpose()?;
+        Ok(())
+    }
+
+    #[setter]
+    fn set_text_y_tolerance_ratio(&mut self, value: Option<f32>) -> PyResult<()> {
+        self.text_settings.y_tolerance_ratio = value
+            .map(|v| NonNegativeF32::new(v, "text_y_tolerance_ratio"))
+            .transpose()?;
+        Ok(())
+    }
+
     #[setter]
     fn set_text_keep_blank_chars(&mut self, value: bool) {
         self.text_settings.keep_blank_chars = value;
@@ -575,6 +1167,42 @@ impl TfSettings {
         self.text_settings.expand_ligatures = value;
     }
 
+    #[setter]
+    fn set_text_normalize_unicode(&mut self, value: bool) {
+        self.text_settings.normalize_unicode = value;
+    }
+
+    #[setter]
+    fn set_text_cell_overlap_ratio(&mut self, value: f32) -> PyResult<()> {
+        self.text_settings.cell_overlap_ratio =
+            BoundedF32::new(value, 0.0, 1.0, "text_cell_overlap_ratio")?;
+        Ok(())
+    }
+
+    #[setter]
+    fn set_text_extra_attrs(&mut self, value: Vec<String>) {
+        self.text_settings.extra_attrs = value;
+    }
+
+    #[setter]
+    fn set_explicit_vertical_lines(&mut self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.explicit_vertical_lines =
+            Self::extract_explicit_lines(value, "explicit_vertical_lines")?;
+        Ok(())
+    }
+
+    #[setter]
+    fn set_explicit_horizontal_lines(&mut self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.explicit_horizontal_lines =
+            Self::extract_explicit_lines(value, "explicit_horizontal_lines")?;
+        Ok(())
+    }
+
+    #[setter]
+    fn set_clip_bbox(&mut self, value: Option<(f32, f32, f32, f32)>) {
+        self.clip_bbox = value;
+    }
+
     // Dataclass-like methods
     fn __repr__(&self) -> String {
         format!(
@@ -584,12 +1212,18 @@ impl TfSettings {
              edge_min_length={}, edge_min_length_prefilter={}, \
              min_words_vertical={}, min_words_horizontal={}, \
              intersection_x_tolerance={}, intersection_y_tolerance={}, \
+             min_gutter_width={}, gutter_coverage_threshold={}, \
+             dash_max_gap={}, min_dash_count={}, respect_edge_style={}, \
              text_x_tolerance={}, text_y_tolerance={}, \
+             text_x_tolerance_ratio={:?}, text_y_tolerance_ratio={:?}, \
              text_keep_blank_chars={}, text_use_text_flow={}, \
              text_read_in_clockwise={}, text_split_at_punctuation={:?}, \
-             text_expand_ligatures={})",
-            Self::strategy_enum_to_str(self.vertical_strategy),
-            Self::strategy_enum_to_str(self.horizontal_strategy),
+             text_expand_ligatures={}, text_normalize_unicode={}, \
+             text_cell_overlap_ratio={}, text_extra_attrs={:?}, \
+             explicit_vertical_lines={:?}, \
+             explicit_horizontal_lines={:?}, clip_bbox={:?})",
+            Self::strategy_set_to_str(self.vertical_strategy),
+            Self::strategy_set_to_str(self.horizontal_strategy),
             self.snap_x_tolerance,
             self.snap_y_tolerance,
             self.join_x_tolerance,
@@ -600,13 +1234,26 @@ impl TfSettings {
             self.min_words_horizontal,
             self.intersection_x_tolerance,
             self.intersection_y_tolerance,
+            self.min_gutter_width,
+            self.gutter_coverage_threshold,
+            self.dash_max_gap,
+            self.min_dash_count,
+            self.respect_edge_style,
             self.text_settings.x_tolerance,
             self.text_settings.y_tolerance,
+            self.text_x_tolerance_ratio(),
+            self.text_y_tolerance_ratio(),
             self.text_settings.keep_blank_chars,
             self.text_settings.use_text_flow,
             self.text_settings.text_read_in_clockwise,
             self.text_split_at_punctuation(),
             self.text_settings.expand_ligatures,
+            self.text_settings.normalize_unicode,
+            self.text_settings.cell_overlap_ratio,
+            self.text_settings.extra_attrs,
+            self.explicit_vertical_lines,
+            self.explicit_horizontal_lines,
+            self.clip_bbox,
         )
     }
 
@@ -624,17 +1271,110 @@ impl TfSettings {
                 && self.min_words_horizontal == other.min_words_horizontal
                 && self.intersection_x_tolerance == other.intersection_x_tolerance
                 && self.intersection_y_tolerance == other.intersection_y_tolerance
+                && self.min_gutter_width == other.min_gutter_width
+                && self.gutter_coverage_threshold == other.gutter_coverage_threshold
+                && self.dash_max_gap == other.dash_max_gap
+                && self.min_dash_count == other.min_dash_count
+                && self.respect_edge_style == other.respect_edge_style
                 && self.text_settings.x_tolerance == other.text_settings.x_tolerance
                 && self.text_settings.y_tolerance == other.text_settings.y_tolerance
+                && self.text_settings.x_tolerance_ratio == other.text_settings.x_tolerance_ratio
+                && self.text_settings.y_tolerance_ratio == other.text_settings.y_tolerance_ratio
                 && self.text_settings.keep_blank_chars == other.text_settings.keep_blank_chars
                 && self.text_settings.use_text_flow == other.text_settings.use_text_flow
                 && self.text_settings.text_read_in_clockwise
                     == other.text_settings.text_read_in_clockwise
                 && self.text_settings.expand_ligatures == other.text_settings.expand_ligatures
+                && self.text_settings.normalize_unicode == other.text_settings.normalize_unicode
+                && self.text_settings.cell_overlap_ratio == other.text_settings.cell_overlap_ratio
+                && self.text_settings.extra_attrs == other.text_settings.extra_attrs
+                && self.clip_bbox == other.clip_bbox
         } else {
             false
         }
     }
+
+    /// Serializes these settings to a plain dict using the same keys
+    /// accepted by the constructor, suitable for persisting to JSON/TOML
+    /// or defining named presets.
+    ///
+    /// # Arguments
+    ///
+    /// * `py` - The Python GIL token.
+    ///
+    /// # Returns
+    ///
+    /// A dict equivalent to what was passed (or would be accepted) by `py_new`.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Py<PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("vertical_strategy", self.vertical_strategy())?;
+        dict.set_item("horizontal_strategy", self.horizontal_strategy())?;
+        dict.set_item("snap_x_tolerance", self.snap_x_tolerance())?;
+        dict.set_item("snap_y_tolerance", self.snap_y_tolerance())?;
+        dict.set_item("join_x_tolerance", self.join_x_tolerance())?;
+        dict.set_item("join_y_tolerance", self.join_y_tolerance())?;
+        dict.set_item("edge_min_length", self.edge_min_length())?;
+        dict.set_item("edge_min_length_prefilter", self.edge_min_length_prefilter())?;
+        dict.set_item("min_words_vertical", self.min_words_vertical())?;
+        dict.set_item("min_words_horizontal", self.min_words_horizontal())?;
+        dict.set_item("intersection_x_tolerance", self.intersection_x_tolerance())?;
+        dict.set_item("intersection_y_tolerance", self.intersection_y_tolerance())?;
+        dict.set_item("min_gutter_width", self.min_gutter_width())?;
+        dict.set_item("gutter_coverage_threshold", self.gutter_coverage_threshold())?;
+        dict.set_item("dash_max_gap", self.dash_max_gap())?;
+        dict.set_item("min_dash_count", self.min_dash_count())?;
+        dict.set_item("respect_edge_style", self.respect_edge_style())?;
+        dict.set_item("text_x_tolerance", self.text_x_tolerance())?;
+        dict.set_item("text_y_tolerance", self.text_y_tolerance())?;
+        dict.set_item("text_x_tolerance_ratio", self.text_x_tolerance_ratio())?;
+        dict.set_item("text_y_tolerance_ratio", self.text_y_tolerance_ratio())?;
+        dict.set_item("text_keep_blank_chars", self.text_keep_blank_chars())?;
+        dict.set_item("text_use_text_flow", self.text_use_text_flow())?;
+        dict.set_item("text_read_in_clockwise", self.text_read_in_clockwise())?;
+        dict.set_item("text_split_at_punctuation", self.text_split_at_punctuation())?;
+        dict.set_item("text_expand_ligatures", self.text_expand_ligatures())?;
+        dict.set_item("text_normalize_unicode", self.text_normalize_unicode())?;
+        dict.set_item("text_cell_overlap_ratio", self.text_cell_overlap_ratio())?;
+        dict.set_item("text_extra_attrs", self.text_extra_attrs())?;
+        dict.set_item("explicit_vertical_lines", self.explicit_vertical_lines(py)?)?;
+        dict.set_item(
+            "explicit_horizontal_lines",
+            self.explicit_horizontal_lines(py)?,
+        )?;
+        dict.set_item("clip_bbox", self.clip_bbox())?;
+        Ok(dict.unbind())
+    }
+
+    /// Reconstructs a `TfSettings` from a dict produced by `to_dict`
+    /// (or any dict accepted by the constructor).
+    ///
+    /// # Arguments
+    ///
+    /// * `dict` - The dict of settings.
+    ///
+    /// # Returns
+    ///
+    /// A new `TfSettings` instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns PyValueError if any stored value is invalid (e.g. negative).
+    #[staticmethod]
+    fn from_dict(dict: &Bound<'_, PyDict>) -> PyResult<Self> {
+        Self::py_new(Some(dict))
+    }
+
+    /// Returns the pickled state for this object, reusing `to_dict`.
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Py<PyDict>> {
+        self.to_dict(py)
+    }
+
+    /// Restores state produced by `__getstate__`, re-running the same
+    /// validation `py_new` does so a corrupted state raises `PyValueError`.
+    fn __setstate__(&mut self, state: &Bound<'_, PyDict>) -> PyResult<()> {
+        *self = Self::py_new(Some(state))?;
+        Ok(())
+    }
 }
 
 /// Specifies how to split words at punctuation characters.
@@ -657,6 +1397,14 @@ pub struct WordsExtractSettings {
     pub x_tolerance: NonNegativeF32,
     /// Y-axis tolerance for grouping characters into lines.
     pub y_tolerance: NonNegativeF32,
+    /// When set, overrides `x_tolerance` with `ratio * char_size`, where
+    /// `char_size` is the trailing character's font size, so letter
+    /// spacing scales with heading vs. body text instead of using one
+    /// fixed gap for the whole page.
+    pub x_tolerance_ratio: Option<NonNegativeF32>,
+    /// When set, overrides `y_tolerance` with `ratio * char_size`, where
+    /// `char_size` is the trailing character's font size.
+    pub y_tolerance_ratio: Option<NonNegativeF32>,
     /// Whether to preserve blank/whitespace characters.
     pub keep_blank_chars: bool,
     /// Whether to use the PDF's text flow order.
@@ -667,6 +1415,20 @@ pub struct WordsExtractSettings {
     pub split_at_punctuation: Option<SplitPunctuation>,
     /// Whether to expand ligatures into individual characters.
     pub expand_ligatures: bool,
+    /// Whether to NFKC-normalize word text, folding trailing combining
+    /// diacritical marks into their base grapheme where possible.
+    pub normalize_unicode: bool,
+    /// Minimum fraction (0.0 to 1.0) of a character's own bbox area that
+    /// must fall inside a table cell for the character to be assigned to
+    /// it during cell text extraction. Validated via `BoundedF32` since,
+    /// unlike most tolerances here, it has a meaningful upper bound.
+    pub cell_overlap_ratio: BoundedF32,
+    /// Character attributes that must be equal between two otherwise
+    /// adjacent characters for them to merge into the same word, in
+    /// addition to the tolerance checks. Recognized names are
+    /// `"fontname"`, `"size"`, and `"upright"`; unrecognized names are
+    /// ignored.
+    pub extra_attrs: Vec<String>,
 }
 
 impl Default for WordsExtractSettings {
@@ -675,11 +1437,16 @@ impl Default for WordsExtractSettings {
         WordsExtractSettings {
             x_tolerance: NonNegativeF32::new_unchecked(DEFAULT_X_TOLERANCE),
             y_tolerance: NonNegativeF32::new_unchecked(DEFAULT_Y_TOLERANCE),
+            x_tolerance_ratio: None,
+            y_tolerance_ratio: None,
             keep_blank_chars: false,
             use_text_flow: false,
             text_read_in_clockwise: true,
             split_at_punctuation: None,
             expand_ligatures: true,
+            normalize_unicode: false,
+            cell_overlap_ratio: BoundedF32::new_unchecked(DEFAULT_CELL_OVERLAP_RATIO, 0.0, 1.0),
+            extra_attrs: Vec::new(),
         }
     }
 }
@@ -730,26 +1497,40 @@ impl WordsExtractSettings {
                 let key = key.to_string();
                 match key.as_str() {
                     "x_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.x_tolerance = NonNegativeF32::new(v, "x_tolerance")?;
                     }
                     "y_tolerance" => {
-                        let v = value.extract::<f32>().unwrap();
+                        let v = value.extract::<f32>()?;
                         settings.y_tolerance = NonNegativeF32::new(v, "y_tolerance")?;
                     }
-                    "keep_blank_chars" => {
-                        settings.keep_blank_chars = value.extract::<bool>().unwrap()
+                    "x_tolerance_ratio" => {
+                        let v: Option<f32> = value.extract()?;
+                        settings.x_tolerance_ratio =
+                            v.map(|v| NonNegativeF32::new(v, "x_tolerance_ratio")).transpose()?;
+                    }
+                    "y_tolerance_ratio" => {
+                        let v: Option<f32> = value.extract()?;
+                        settings.y_tolerance_ratio =
+                            v.map(|v| NonNegativeF32::new(v, "y_tolerance_ratio")).transpose()?;
                     }
-                    "use_text_flow" => settings.use_text_flow = value.extract::<bool>().unwrap(),
+                    "keep_blank_chars" => settings.keep_blank_chars = value.extract::<bool>()?,
+                    "use_text_flow" => settings.use_text_flow = value.extract::<bool>()?,
                     "text_read_in_clockwise" => {
-                        settings.text_read_in_clockwise = value.extract::<bool>().unwrap()
+                        settings.text_read_in_clockwise = value.extract::<bool>()?
                     }
                     "split_at_punctuation" => {
-                        let split_value: Option<&str> = value.extract().unwrap();
+                        let split_value: Option<&str> = value.extract()?;
                         settings.split_at_punctuation = Self::str_to_split_punctuation(split_value);
                     }
-                    "expand_ligatures" => {
-                        settings.expand_ligatures = value.extract::<bool>().unwrap()
+                    "expand_ligatures" => settings.expand_ligatures = value.extract::<bool>()?,
+                    "normalize_unicode" => settings.normalize_unicode = value.extract::<bool>()?,
+                    "cell_overlap_ratio" => {
+                        let v = value.extract::<f32>()?;
+                        settings.cell_overlap_ratio = BoundedF32::new(v, 0.0, 1.0, "cell_overlap_ratio")?;
+                    }
+                    "extra_attrs" => {
+                        settings.extra_attrs = value.extract::<Vec<String>>()?;
                     }
                     _ => (), // Ignore unknown settings
                 }
@@ -769,6 +1550,16 @@ impl WordsExtractSettings {
         self.y_tolerance.into_inner()
     }
 
+    #[getter]
+    fn x_tolerance_ratio(&self) -> Option<f32> {
+        self.x_tolerance_ratio.map(|v| v.into_inner())
+    }
+
+    #[getter]
+    fn y_tolerance_ratio(&self) -> Option<f32> {
+        self.y_tolerance_ratio.map(|v| v.into_inner())
+    }
+
     #[getter]
     fn keep_blank_chars(&self) -> bool {
         self.keep_blank_chars
@@ -794,6 +1585,21 @@ impl WordsExtractSettings {
         self.expand_ligatures
     }
 
+    #[getter]
+    fn normalize_unicode(&self) -> bool {
+        self.normalize_unicode
+    }
+
+    #[getter]
+    fn cell_overlap_ratio(&self) -> f32 {
+        self.cell_overlap_ratio.into_inner()
+    }
+
+    #[getter]
+    fn extra_attrs(&self) -> Vec<String> {
+        self.extra_attrs.clone()
+    }
+
     // Setters
     #[setter]
     fn set_x_tolerance(&mut self, value: f32) -> PyResult<()> {
@@ -807,6 +1613,22 @@ impl WordsExtractSettings {
         Ok(())
     }
 
+    #[setter]
+    fn set_x_tolerance_ratio(&mut self, value: Option<f32>) -> PyResult<()> {
+        self.x_tolerance_ratio = value
+            .map(|v| NonNegativeF32::new(v, "x_tolerance_ratio"))
+            .transpose()?;
+        Ok(())
+    }
+
+    #[setter]
+    fn set_y_tolerance_ratio(&mut self, value: Option<f32>) -> PyResult<()> {
+        self.y_tolerance_ratio = value
+            .map(|v| NonNegativeF32::new(v, "y_tolerance_ratio"))
+            .transpose()?;
+        Ok(())
+    }
+
     #[setter]
     fn set_keep_blank_chars(&mut self, value: bool) {
         self.keep_blank_chars = value;
@@ -832,20 +1654,43 @@ impl WordsExtractSettings {
         self.expand_ligatures = value;
     }
 
+    #[setter]
+    fn set_normalize_unicode(&mut self, value: bool) {
+        self.normalize_unicode = value;
+    }
+
+    #[setter]
+    fn set_cell_overlap_ratio(&mut self, value: f32) -> PyResult<()> {
+        self.cell_overlap_ratio = BoundedF32::new(value, 0.0, 1.0, "cell_overlap_ratio")?;
+        Ok(())
+    }
+
+    #[setter]
+    fn set_extra_attrs(&mut self, value: Vec<String>) {
+        self.extra_attrs = value;
+    }
+
     // Dataclass-like methods
     fn __repr__(&self) -> String {
         format!(
             "WordsExtractSettings(x_tolerance={}, y_tolerance={}, \
+             x_tolerance_ratio={:?}, y_tolerance_ratio={:?}, \
              keep_blank_chars={}, use_text_flow={}, \
              text_read_in_clockwise={}, split_at_punctuation={:?}, \
-             expand_ligatures={})",
+             expand_ligatures={}, normalize_unicode={}, \
+             cell_overlap_ratio={}, extra_attrs={:?})",
             self.x_tolerance,
             self.y_tolerance,
+            self.x_tolerance_ratio(),
+            self.y_tolerance_ratio(),
             self.keep_blank_chars,
             self.use_text_flow,
             self.text_read_in_clockwise,
             self.split_punctuation_to_str(),
             self.expand_ligatures,
+            self.normalize_unicode,
+            self.cell_overlap_ratio,
+            self.extra_attrs,
         )
     }
 
@@ -853,14 +1698,77 @@ impl WordsExtractSettings {
         if let Ok(other) = other.extract::<WordsExtractSettings>() {
             self.x_tolerance == other.x_tolerance
                 && self.y_tolerance == other.y_tolerance
+                && self.x_tolerance_ratio == other.x_tolerance_ratio
+                && self.y_tolerance_ratio == other.y_tolerance_ratio
                 && self.keep_blank_chars == other.keep_blank_chars
                 && self.use_text_flow == other.use_text_flow
                 && self.text_read_in_clockwise == other.text_read_in_clockwise
                 && self.expand_ligatures == other.expand_ligatures
+                && self.normalize_unicode == other.normalize_unicode
+                && self.cell_overlap_ratio == other.cell_overlap_ratio
+                && self.extra_attrs == other.extra_attrs
         } else {
             false
         }
     }
+
+    /// Serializes these settings to a plain dict using the same keys
+    /// accepted by the constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `py` - The Python GIL token.
+    ///
+    /// # Returns
+    ///
+    /// A dict equivalent to what was passed (or would be accepted) by `py_new`.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Py<PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("x_tolerance", self.x_tolerance())?;
+        dict.set_item("y_tolerance", self.y_tolerance())?;
+        dict.set_item("x_tolerance_ratio", self.x_tolerance_ratio())?;
+        dict.set_item("y_tolerance_ratio", self.y_tolerance_ratio())?;
+        dict.set_item("keep_blank_chars", self.keep_blank_chars())?;
+        dict.set_item("use_text_flow", self.use_text_flow())?;
+        dict.set_item("text_read_in_clockwise", self.text_read_in_clockwise())?;
+        dict.set_item("split_at_punctuation", self.split_at_punctuation())?;
+        dict.set_item("expand_ligatures", self.expand_ligatures())?;
+        dict.set_item("normalize_unicode", self.normalize_unicode())?;
+        dict.set_item("cell_overlap_ratio", self.cell_overlap_ratio())?;
+        dict.set_item("extra_attrs", self.extra_attrs())?;
+        Ok(dict.unbind())
+    }
+
+    /// Reconstructs a `WordsExtractSettings` from a dict produced by
+    /// `to_dict` (or any dict accepted by the constructor).
+    ///
+    /// # Arguments
+    ///
+    /// * `dict` - The dict of settings.
+    ///
+    /// # Returns
+    ///
+    /// A new `WordsExtractSettings` instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns PyValueError if any stored value is invalid (e.g. negative).
+    #[staticmethod]
+    fn from_dict(dict: &Bound<'_, PyDict>) -> PyResult<Self> {
+        Self::py_new(Some(dict))
+    }
+
+    /// Returns the pickled state for this object, reusing `to_dict`.
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Py<PyDict>> {
+        self.to_dict(py)
+    }
+
+    /// Restores state produced by `__getstate__`, re-running the same
+    /// validation `py_new` does so a corrupted state raises `PyValueError`.
+    fn __setstate__(&mut self, state: &Bound<'_, PyDict>) -> PyResult<()> {
+        *self = Self::py_new(Some(state))?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -871,8 +1779,14 @@ mod tests {
     #[test]
     fn test_tf_settings_default() {
         let settings = TfSettings::default();
-        assert_eq!(settings.vertical_strategy, StrategyType::LinesStrict);
-        assert_eq!(settings.horizontal_strategy, StrategyType::LinesStrict);
+        assert_eq!(
+            settings.vertical_strategy,
+            StrategySet::from(StrategyType::LinesStrict)
+        );
+        assert_eq!(
+            settings.horizontal_strategy,
+            StrategySet::from(StrategyType::LinesStrict)
+        );
         assert_eq!(settings.snap_x_tolerance.into_inner(), 3.0);
         assert_eq!(settings.snap_y_tolerance.into_inner(), 3.0);
         assert_eq!(settings.join_x_tolerance.into_inner(), 3.0);
@@ -883,6 +1797,11 @@ mod tests {
         assert_eq!(settings.min_words_horizontal, 1);
         assert_eq!(settings.intersection_x_tolerance.into_inner(), 3.0);
         assert_eq!(settings.intersection_y_tolerance.into_inner(), 3.0);
+        assert_eq!(settings.min_gutter_width.into_inner(), 3.0);
+        assert_eq!(settings.gutter_coverage_threshold, 0);
+        assert_eq!(settings.dash_max_gap.into_inner(), 0.0);
+        assert_eq!(settings.min_dash_count, 4);
+        assert!(!settings.respect_edge_style);
     }
 
     #[test]
@@ -898,28 +1817,122 @@ mod tests {
     fn test_non_negative_f32_invalid() {
         let result = NonNegativeF32::new(-1.0, "test_field");
         assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert_eq!(err.field_name, "test_field");
-        assert_eq!(err.value, -1.0);
+        match result.unwrap_err() {
+            NonNegativeF32Error::Negative(err) => {
+                assert_eq!(err.field_name, "test_field");
+                assert_eq!(err.value, -1.0);
+            }
+            other => panic!("expected NegativeValueError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_non_negative_f32_rejects_nan() {
+        let result = NonNegativeF32::new(f32::NAN, "nan_field");
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            NonNegativeF32Error::NonFinite(err) => {
+                assert_eq!(err.field_name, "nan_field");
+                assert!(err.value.is_nan());
+            }
+            other => panic!("expected NonFiniteValueError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_non_negative_f32_rejects_infinity() {
+        let result = NonNegativeF32::new(f32::INFINITY, "inf_field");
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            NonNegativeF32Error::NonFinite(err) => {
+                assert_eq!(err.field_name, "inf_field");
+                assert_eq!(err.value, f32::INFINITY);
+            }
+            other => panic!("expected NonFiniteValueError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_non_negative_f32_try_from() {
+        let val: NonNegativeF32 = 3.0.try_into().unwrap();
+        assert_eq!(val.into_inner(), 3.0);
+
+        let result: Result<NonNegativeF32, _> = (-1.0).try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bounded_f32_valid() {
+        let val = BoundedF32::new(0.5, 0.0, 1.0, "ratio").unwrap();
+        assert_eq!(val.into_inner(), 0.5);
+        assert_eq!(val.min(), 0.0);
+        assert_eq!(val.max(), 1.0);
+    }
+
+    #[test]
+    fn test_bounded_f32_rejects_below_min() {
+        let result = BoundedF32::new(-0.1, 0.0, 1.0, "ratio");
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            BoundedF32Error::OutOfRange(err) => {
+                assert_eq!(err.field_name, "ratio");
+                assert_eq!(err.min, 0.0);
+                assert_eq!(err.max, 1.0);
+            }
+            other => panic!("expected OutOfRangeError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_bounded_f32_rejects_above_max() {
+        let result = BoundedF32::new(1.1, 0.0, 1.0, "ratio");
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            BoundedF32Error::OutOfRange(err) => {
+                assert_eq!(err.value, 1.1);
+            }
+            other => panic!("expected OutOfRangeError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_bounded_f32_rejects_nan() {
+        let result = BoundedF32::new(f32::NAN, 0.0, 1.0, "ratio");
+        assert!(matches!(result, Err(BoundedF32Error::NonFinite(_))));
     }
 
     #[test]
-    fn test_strategy_str_to_enum() {
+    fn test_bounded_f32_error_message_states_interval() {
+        let err = BoundedF32::new(2.0, 0.0, 1.0, "cell_overlap_ratio").unwrap_err();
         assert_eq!(
-            TfSettings::strategy_str_to_enum("lines"),
-            StrategyType::Lines
+            err.to_string(),
+            "cell_overlap_ratio must be between 0 and 1, got 2"
         );
+    }
+
+    #[test]
+    fn test_strategy_type_from_str() {
+        assert_eq!("lines".parse::<StrategyType>().unwrap(), StrategyType::Lines);
         assert_eq!(
-            TfSettings::strategy_str_to_enum("lines_strict"),
+            "lines_strict".parse::<StrategyType>().unwrap(),
             StrategyType::LinesStrict
         );
-        assert_eq!(TfSettings::strategy_str_to_enum("text"), StrategyType::Text);
+        assert_eq!("text".parse::<StrategyType>().unwrap(), StrategyType::Text);
+        assert_eq!(
+            "explicit".parse::<StrategyType>().unwrap(),
+            StrategyType::Explicit
+        );
+        assert_eq!(
+            "projection".parse::<StrategyType>().unwrap(),
+            StrategyType::Projection
+        );
     }
 
     #[test]
-    #[should_panic(expected = "Invalid strategy")]
-    fn test_strategy_str_to_enum_invalid() {
-        TfSettings::strategy_str_to_enum("invalid");
+    fn test_strategy_type_from_str_invalid() {
+        let err = "invalid".parse::<StrategyType>().unwrap_err();
+        assert_eq!(err.got, "invalid");
+        assert!(err.to_string().contains("invalid strategy"));
     }
 
     #[test]
@@ -933,6 +1946,14 @@ mod tests {
             "lines_strict"
         );
         assert_eq!(TfSettings::strategy_enum_to_str(StrategyType::Text), "text");
+        assert_eq!(
+            TfSettings::strategy_enum_to_str(StrategyType::Explicit),
+            "explicit"
+        );
+        assert_eq!(
+            TfSettings::strategy_enum_to_str(StrategyType::Projection),
+            "projection"
+        );
     }
 
     #[test]
@@ -950,6 +1971,76 @@ mod tests {
         assert_eq!(StrategyType::Text & 7u8, 4u8); // 7 = Lines | LinesStrict | Text
     }
 
+    #[test]
+    fn test_strategy_set_contains_and_insert() {
+        let mut set = StrategySet::EMPTY;
+        assert!(!set.contains(StrategyType::Lines));
+
+        set.insert(StrategyType::Lines);
+        assert!(set.contains(StrategyType::Lines));
+        assert!(!set.contains(StrategyType::Text));
+
+        set.insert(StrategyType::Text);
+        assert!(set.contains(StrategyType::Lines));
+        assert!(set.contains(StrategyType::Text));
+        assert!(!set.contains(StrategyType::LinesStrict));
+    }
+
+    #[test]
+    fn test_strategy_set_iterator() {
+        let mut set = StrategySet::EMPTY;
+        set.insert(StrategyType::Text);
+        set.insert(StrategyType::Lines);
+
+        let collected: Vec<StrategyType> = set.collect();
+        assert_eq!(collected, vec![StrategyType::Lines, StrategyType::Text]);
+    }
+
+    #[test]
+    fn test_strategy_set_from_str_single() {
+        let set: StrategySet = "lines".parse().unwrap();
+        assert!(set.contains(StrategyType::Lines));
+        assert!(!set.contains(StrategyType::Text));
+    }
+
+    #[test]
+    fn test_strategy_set_from_str_combined() {
+        let set: StrategySet = "lines+text".parse().unwrap();
+        assert!(set.contains(StrategyType::Lines));
+        assert!(set.contains(StrategyType::Text));
+        assert!(!set.contains(StrategyType::LinesStrict));
+    }
+
+    #[test]
+    fn test_strategy_set_from_str_invalid() {
+        let err = "lines+bogus".parse::<StrategySet>().unwrap_err();
+        assert_eq!(err.got, "bogus");
+    }
+
+    #[test]
+    fn test_strategy_set_to_str_canonical() {
+        let mut set = StrategySet::EMPTY;
+        set.insert(StrategyType::Text);
+        set.insert(StrategyType::Lines);
+        assert_eq!(TfSettings::strategy_set_to_str(set), "lines+text");
+    }
+
+    #[test]
+    fn test_explicit_line_coordinate_rejects_negative() {
+        let result = NonNegativeF32::new(-5.0, "explicit_vertical_lines");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_explicit_line_coordinate_holds_value() {
+        let coord = NonNegativeF32::new(42.0, "explicit_vertical_lines").unwrap();
+        let line = ExplicitLine::Coordinate(coord);
+        match line {
+            ExplicitLine::Coordinate(v) => assert_eq!(v.into_inner(), 42.0),
+            ExplicitLine::Full(_) => panic!("expected Coordinate variant"),
+        }
+    }
+
     // WordsExtractSettings tests
     #[test]
     fn test_words_extract_settings_default() {
@@ -961,6 +2052,7 @@ mod tests {
         assert!(settings.text_read_in_clockwise);
         assert!(settings.split_at_punctuation.is_none());
         assert!(settings.expand_ligatures);
+        assert_eq!(settings.cell_overlap_ratio.into_inner(), 0.5);
     }
 
     #[test]
@@ -1006,6 +2098,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tf_settings_dict_round_trip() {
+        Python::attach(|py| {
+            let mut settings = TfSettings::default();
+            settings.vertical_strategy = "lines+text".parse().unwrap();
+            settings.snap_x_tolerance = NonNegativeF32::new(5.0, "snap_x_tolerance").unwrap();
+
+            let dict = settings.to_dict(py).unwrap();
+            let restored = TfSettings::from_dict(dict.bind(py)).unwrap();
+
+            assert_eq!(restored.vertical_strategy, settings.vertical_strategy);
+            assert_eq!(restored.snap_x_tolerance, settings.snap_x_tolerance);
+        });
+    }
+
+    #[test]
+    fn test_tf_settings_from_dict_rejects_invalid_value() {
+        Python::attach(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("snap_x_tolerance", -1.0).unwrap();
+            assert!(TfSettings::from_dict(&dict).is_err());
+        });
+    }
+
+    #[test]
+    fn test_tf_settings_pickle_round_trip() {
+        Python::attach(|py| {
+            let mut settings = TfSettings::default();
+            settings.vertical_strategy = "lines+text".parse().unwrap();
+            settings.snap_x_tolerance = NonNegativeF32::new(5.0, "snap_x_tolerance").unwrap();
+
+            let state = settings.__getstate__(py).unwrap();
+            let mut restored = TfSettings::default();
+            restored.__setstate__(state.bind(py)).unwrap();
+
+            assert_eq!(restored.vertical_strategy, settings.vertical_strategy);
+            assert_eq!(restored.snap_x_tolerance, settings.snap_x_tolerance);
+        });
+    }
+
+    #[test]
+    fn test_tf_settings_setstate_rejects_invalid_value() {
+        Python::attach(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("snap_x_tolerance", -1.0).unwrap();
+            let mut settings = TfSettings::default();
+            assert!(settings.__setstate__(&dict).is_err());
+        });
+    }
+
+    #[test]
+    fn test_words_extract_settings_dict_round_trip() {
+        Python::attach(|py| {
+            let mut settings = WordsExtractSettings::default();
+            settings.x_tolerance = NonNegativeF32::new(7.0, "x_tolerance").unwrap();
+            settings.split_at_punctuation = Some(SplitPunctuation::All);
+
+            let dict = settings.to_dict(py).unwrap();
+            let restored = WordsExtractSettings::from_dict(dict.bind(py)).unwrap();
+
+            assert_eq!(restored.x_tolerance, settings.x_tolerance);
+            assert_eq!(restored.split_at_punctuation(), Some("all".to_string()));
+        });
+    }
+
+    #[test]
+    fn test_words_extract_settings_pickle_round_trip() {
+        Python::attach(|py| {
+            let mut settings = WordsExtractSettings::default();
+            settings.x_tolerance = NonNegativeF32::new(7.0, "x_tolerance").unwrap();
+            settings.split_at_punctuation = Some(SplitPunctuation::All);
+
+            let state = settings.__getstate__(py).unwrap();
+            let mut restored = WordsExtractSettings::default();
+            restored.__setstate__(state.bind(py)).unwrap();
+
+            assert_eq!(restored.x_tolerance, settings.x_tolerance);
+            assert_eq!(restored.split_at_punctuation(), Some("all".to_string()));
+        });
+    }
+
+    #[test]
+    fn test_words_extract_settings_setstate_rejects_invalid_value() {
+        Python::attach(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("x_tolerance", -1.0).unwrap();
+            let mut settings = WordsExtractSettings::default();
+            assert!(settings.__setstate__(&dict).is_err());
+        });
+    }
+
     // NonNegativeF32 additional tests
     #[test]
     fn test_non_negative_f32_zero_is_valid() {
@@ -1025,9 +2208,13 @@ mod tests {
     fn test_non_negative_f32_negative_is_invalid() {
         let negative = NonNegativeF32::new(-0.001, "my_field");
         assert!(negative.is_err());
-        let err = negative.unwrap_err();
-        assert_eq!(err.field_name, "my_field");
-        assert!(err.value < 0.0);
+        match negative.unwrap_err() {
+            NonNegativeF32Error::Negative(err) => {
+                assert_eq!(err.field_name, "my_field");
+                assert!(err.value < 0.0);
+            }
+            other => panic!("expected NegativeValueError, got {other:?}"),
+        }
     }
 
     #[test]
@@ -0,0 +1,311 @@
+use pyo3::prelude::*;
+
+/// Per-column numeric aggregates computed by
+/// [`crate::tables::Table::column_stats`].
+///
+/// `count`/`min`/`max`/`mean`/`variance`/`stddev` are always computed;
+/// `min`/`max`/`mean` are `None` when no numeric value was found in the
+/// column, and `variance`/`stddev` additionally require at least two values
+/// (Bessel's correction divides by `count - 1`). Any quantiles requested via
+/// `column_stats`'s `quantiles` argument are retrievable afterward through
+/// [`ColumnStats::quantile`].
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ColumnStats {
+    /// The number of cells in the column that parsed as a number.
+    #[pyo3(get)]
+    pub count: usize,
+    /// The smallest value seen, if any.
+    #[pyo3(get)]
+    pub min: Option<f64>,
+    /// The largest value seen, if any.
+    #[pyo3(get)]
+    pub max: Option<f64>,
+    /// The arithmetic mean, if any values were seen.
+    #[pyo3(get)]
+    pub mean: Option<f64>,
+    /// The sample variance (divided by `count - 1`), if at least two values
+    /// were seen.
+    #[pyo3(get)]
+    pub variance: Option<f64>,
+    /// The sample standard deviation, if at least two values were seen.
+    #[pyo3(get)]
+    pub stddev: Option<f64>,
+    quantile_estimates: Vec<(f64, f64)>,
+}
+
+#[pymethods]
+impl ColumnStats {
+    /// Returns the P²-estimated value at quantile `p`, or `None` if `p`
+    /// wasn't one of the quantiles requested when these stats were computed.
+    fn quantile(&self, p: f64) -> Option<f64> {
+        self.quantile_estimates
+            .iter()
+            .find(|(q, _)| (*q - p).abs() < 1e-9)
+            .map(|(_, v)| *v)
+    }
+}
+
+/// Numerically stable single-pass mean/variance accumulator, using
+/// Welford's online algorithm: for each new value `x`, `n` is incremented,
+/// `mean` is nudged by `(x - mean) / n`, and `m2` accumulates
+/// `(x - mean_before) * (x - mean_after)`, so `variance = m2 / (n - 1)`
+/// without ever re-reading earlier samples.
+#[derive(Debug, Clone, Default)]
+struct WelfordAccumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl WelfordAccumulator {
+    fn update(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        let delta2 = x - self.mean;
+        self.m2 += delta * delta2;
+    }
+
+    fn variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.m2 / (self.count - 1) as f64)
+        }
+    }
+
+    fn stddev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+}
+
+/// The five tracked markers of the P² algorithm: their heights (`q`),
+/// actual sample-count positions (`n`), desired (possibly fractional)
+/// positions (`np`), and the per-sample increments to those desired
+/// positions (`dn`).
+#[derive(Debug, Clone)]
+struct P2Markers {
+    q: [f64; 5],
+    n: [f64; 5],
+    np: [f64; 5],
+    dn: [f64; 5],
+}
+
+/// A streaming estimator for a single quantile `p`, using the P² algorithm
+/// (Jain & Chlamtac): after the first 5 samples seed the markers, every
+/// further sample only updates 5 running scalars via a piecewise-parabolic
+/// (falling back to linear) adjustment, so memory stays O(1) regardless of
+/// how many samples are seen.
+#[derive(Debug, Clone)]
+struct P2Quantile {
+    p: f64,
+    initial: Vec<f64>,
+    markers: Option<P2Markers>,
+}
+
+impl P2Quantile {
+    fn new(p: f64) -> Self {
+        Self {
+            p,
+            initial: Vec::with_capacity(5),
+            markers: None,
+        }
+    }
+
+    fn update(&mut self, x: f64) {
+        let Some(markers) = &mut self.markers else {
+            self.initial.push(x);
+            if self.initial.len() == 5 {
+                self.initial
+                    .sort_by(|a, b| a.partial_cmp(b).expect("non-NaN sample"));
+                let q = [
+                    self.initial[0],
+                    self.initial[1],
+                    self.initial[2],
+                    self.initial[3],
+                    self.initial[4],
+                ];
+                self.markers = Some(P2Markers {
+                    q,
+                    n: [1.0, 2.0, 3.0, 4.0, 5.0],
+                    np: [
+                        1.0,
+                        1.0 + 2.0 * self.p,
+                        1.0 + 4.0 * self.p,
+                        3.0 + 2.0 * self.p,
+                        5.0,
+                    ],
+                    dn: [0.0, self.p / 2.0, self.p, (1.0 + self.p) / 2.0, 1.0],
+                });
+            }
+            return;
+        };
+
+        let k = if x < markers.q[0] {
+            markers.q[0] = x;
+            0
+        } else if x >= markers.q[4] {
+            markers.q[4] = x;
+            3
+        } else {
+            (0..4)
+                .find(|&i| markers.q[i] <= x && x < markers.q[i + 1])
+                .unwrap_or(3)
+        };
+
+        for n in &mut markers.n[(k + 1)..5] {
+            *n += 1.0;
+        }
+        for i in 0..5 {
+            markers.np[i] += markers.dn[i];
+        }
+
+        for i in 1..4 {
+            let d = markers.np[i] - markers.n[i];
+            if (d >= 1.0 && markers.n[i + 1] - markers.n[i] > 1.0)
+                || (d <= -1.0 && markers.n[i - 1] - markers.n[i] < -1.0)
+            {
+                let d_sign = d.signum();
+                let parabolic = Self::parabolic(markers, i, d_sign);
+                markers.q[i] = if markers.q[i - 1] < parabolic && parabolic < markers.q[i + 1] {
+                    parabolic
+                } else {
+                    Self::linear(markers, i, d_sign)
+                };
+                markers.n[i] += d_sign;
+            }
+        }
+    }
+
+    /// The P² piecewise-parabolic adjustment formula for marker `i`.
+    fn parabolic(m: &P2Markers, i: usize, d: f64) -> f64 {
+        let (qi, qim1, qip1) = (m.q[i], m.q[i - 1], m.q[i + 1]);
+        let (ni, nim1, nip1) = (m.n[i], m.n[i - 1], m.n[i + 1]);
+        qi + d / (nip1 - nim1)
+            * ((ni - nim1 + d) * (qip1 - qi) / (nip1 - ni)
+                + (nip1 - ni - d) * (qi - qim1) / (ni - nim1))
+    }
+
+    /// The linear fallback used when the parabolic estimate would violate
+    /// marker ordering.
+    fn linear(m: &P2Markers, i: usize, d: f64) -> f64 {
+        let j = (i as f64 + d) as usize;
+        m.q[i] + d * (m.q[j] - m.q[i]) / (m.n[j] - m.n[i])
+    }
+
+    /// Returns the current estimate for quantile `p`, or `None` if fewer
+    /// than one sample has been seen.
+    fn estimate(&self) -> Option<f64> {
+        if let Some(markers) = &self.markers {
+            return Some(markers.q[2]);
+        }
+        if self.initial.is_empty() {
+            return None;
+        }
+        let mut sorted = self.initial.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).expect("non-NaN sample"));
+        let idx = (((sorted.len() - 1) as f64) * self.p).round() as usize;
+        Some(sorted[idx])
+    }
+}
+
+/// Computes count/min/max/mean/variance/stddev plus any requested quantiles
+/// over `values` in a single pass, combining a [`WelfordAccumulator`] with
+/// one [`P2Quantile`] tracker per requested quantile.
+pub(crate) fn compute_column_stats(
+    values: impl Iterator<Item = f64>,
+    quantiles: &[f64],
+) -> ColumnStats {
+    let mut welford = WelfordAccumulator::default();
+    let mut min: Option<f64> = None;
+    let mut max: Option<f64> = None;
+    let mut trackers: Vec<P2Quantile> = quantiles.iter().map(|&p| P2Quantile::new(p)).collect();
+
+    for value in values {
+        welford.update(value);
+        min = Some(min.map_or(value, |m: f64| m.min(value)));
+        max = Some(max.map_or(value, |m: f64| m.max(value)));
+        for tracker in &mut trackers {
+            tracker.update(value);
+        }
+    }
+
+    ColumnStats {
+        count: welford.count as usize,
+        min,
+        max,
+        mean: (welford.count > 0).then_some(welford.mean),
+        variance: welford.variance(),
+        stddev: welford.stddev(),
+        quantile_estimates: quantiles
+            .iter()
+            .zip(trackers.iter())
+            .filter_map(|(&p, tracker)| tracker.estimate().map(|v| (p, v)))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_welford_matches_naive_mean_and_variance() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut acc = WelfordAccumulator::default();
+        for &v in &values {
+            acc.update(v);
+        }
+
+        assert!((acc.mean - 5.0).abs() < 1e-9);
+        // Population variance of this classic example is 4.0 (32 / 8); the
+        // sample variance we compute divides by (n - 1) instead: 32 / 7.
+        assert!((acc.variance().unwrap() - 32.0 / 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_welford_variance_is_none_below_two_samples() {
+        let mut acc = WelfordAccumulator::default();
+        assert!(acc.variance().is_none());
+        acc.update(1.0);
+        assert!(acc.variance().is_none());
+        acc.update(2.0);
+        assert!(acc.variance().is_some());
+    }
+
+    #[test]
+    fn test_p2_quantile_median_approximates_sorted_midpoint() {
+        let values: Vec<f64> = (1..=1001).map(|i| i as f64).collect();
+        let mut tracker = P2Quantile::new(0.5);
+        for &v in &values {
+            tracker.update(v);
+        }
+
+        // The true median of 1..=1001 is 501.0; P2 is an approximation.
+        assert!((tracker.estimate().unwrap() - 501.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_compute_column_stats_reports_count_min_max_and_quantile() {
+        let stats = compute_column_stats([1.0, 2.0, 3.0, 4.0, 5.0].into_iter(), &[0.5]);
+
+        assert_eq!(stats.count, 5);
+        assert_eq!(stats.min, Some(1.0));
+        assert_eq!(stats.max, Some(5.0));
+        assert_eq!(stats.mean, Some(3.0));
+        assert!(stats.quantile(0.5).is_some());
+        assert!(stats.quantile(0.9).is_none());
+    }
+
+    #[test]
+    fn test_compute_column_stats_empty_input_has_no_aggregates() {
+        let stats = compute_column_stats(std::iter::empty(), &[0.5]);
+
+        assert_eq!(stats.count, 0);
+        assert_eq!(stats.min, None);
+        assert_eq!(stats.mean, None);
+        assert_eq!(stats.variance, None);
+        assert!(stats.quantile(0.5).is_none());
+    }
+}
@@ -1,13 +1,19 @@
 use crate::edges::*;
+use crate::index::{build_string_index, StringIndex};
 use crate::objects::*;
 use crate::pages::Page;
 use crate::settings::*;
+use crate::stats::{compute_column_stats, ColumnStats};
 use crate::words::*;
 use ordered_float::OrderedFloat;
+use pdfium_render::prelude::PdfColor;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use std::cmp;
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::rc::Rc;
+use std::str::FromStr;
+use thiserror::Error;
 
 /// Specifies whether a cell group represents a row or column.
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -88,7 +94,10 @@ fn get_axis_value(cell: &BboxKey, axis: usize) -> OrderedFloat<f32> {
 
 /// Represents a single cell in a table.
 ///
-/// Each cell has a bounding box and optional text content.
+/// Each cell has a bounding box and optional text content. A cell whose
+/// bounding box spans multiple grid separators (because an interior rule is
+/// absent) covers more than one row and/or column, tracked by `row_start`/
+/// `rowspan` and `col_start`/`colspan`.
 #[pyclass]
 #[derive(Debug, Clone)]
 pub struct TableCell {
@@ -96,6 +105,14 @@ pub struct TableCell {
     pub text: String,
     /// The bounding box of the cell.
     pub bbox: BboxKey,
+    /// The index of the first column this cell covers.
+    pub col_start: usize,
+    /// The number of columns this cell spans.
+    pub colspan: usize,
+    /// The index of the first row this cell covers.
+    pub row_start: usize,
+    /// The number of rows this cell spans.
+    pub rowspan: usize,
 }
 
 #[pymethods]
@@ -116,12 +133,37 @@ impl TableCell {
             self.bbox.3.into_inner(),
         )
     }
+
+    /// Returns the index of the first column this cell covers.
+    #[getter]
+    fn col_start(&self) -> usize {
+        self.col_start
+    }
+
+    /// Returns the number of columns this cell spans.
+    #[getter]
+    fn colspan(&self) -> usize {
+        self.colspan
+    }
+
+    /// Returns the index of the first row this cell covers.
+    #[getter]
+    fn row_start(&self) -> usize {
+        self.row_start
+    }
+
+    /// Returns the number of rows this cell spans.
+    #[getter]
+    fn rowspan(&self) -> usize {
+        self.rowspan
+    }
 }
 
 /// Represents a table extracted from a PDF page.
 ///
 /// A table consists of cells organized in a grid structure.
 #[pyclass]
+#[derive(Debug)]
 pub struct Table {
     /// All cells in the table.
     pub cells: Vec<TableCell>,
@@ -134,6 +176,81 @@ pub struct Table {
     #[pyo3(get)]
     pub text_extracted: bool,
 }
+/// Horizontal alignment for a column in [`Table::to_aligned_string`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+impl FromStr for Align {
+    type Err = InvalidAlignError;
+
+    /// Parses an alignment string ("left", "right", or "center").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "left" => Ok(Align::Left),
+            "right" => Ok(Align::Right),
+            "center" => Ok(Align::Center),
+            _ => Err(InvalidAlignError { got: s.to_string() }),
+        }
+    }
+}
+
+impl Align {
+    /// Pads `text` to `width` display columns, measuring it with
+    /// `text_width`.
+    fn pad(self, text: &str, width: usize, text_width: impl Fn(&str) -> usize) -> String {
+        let pad = width.saturating_sub(text_width(text));
+        match self {
+            Align::Left => format!("{text}{}", " ".repeat(pad)),
+            Align::Right => format!("{}{text}", " ".repeat(pad)),
+            Align::Center => {
+                let left = pad / 2;
+                let right = pad - left;
+                format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+            }
+        }
+    }
+}
+
+/// Error type for an unrecognized alignment string.
+#[derive(Debug, Clone, Error)]
+#[error("invalid alignment {got:?}, expected one of \"left\", \"right\", \"center\"")]
+struct InvalidAlignError {
+    got: String,
+}
+
+impl From<InvalidAlignError> for PyErr {
+    fn from(err: InvalidAlignError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Estimates the terminal display width of `s`, treating common East Asian
+/// wide/fullwidth characters as occupying two columns and combining marks as
+/// occupying none, instead of the one-column-per-`char` assumption used
+/// elsewhere.
+fn display_width(s: &str) -> usize {
+    s.chars().map(char_display_width).sum()
+}
+
+/// Estimates the terminal display width of a single character.
+fn char_display_width(c: char) -> usize {
+    match c as u32 {
+        0x0300..=0x036F => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
 #[pymethods]
 impl Table {
     /// Returns a clone of all cells in the table.
@@ -152,6 +269,582 @@ impl Table {
             self.bbox.3.into_inner(),
         )
     }
+
+    /// Materializes the table as a rectangular grid of cell text.
+    ///
+    /// Walks [`Table::rows`], placing each cell's text at every grid
+    /// position it covers. When `repeat_spanned` is `false` (the default),
+    /// only a spanning cell's top-left position holds its text and the rest
+    /// of its span is left blank (`None`); when `true`, the text is repeated
+    /// into every position the span covers.
+    #[pyo3(signature = (repeat_spanned=false))]
+    fn to_grid(&self, repeat_spanned: bool) -> Vec<Vec<Option<String>>> {
+        self.rows()
+            .iter()
+            .enumerate()
+            .map(|(row_idx, row)| {
+                row.cells
+                    .iter()
+                    .enumerate()
+                    .map(|(col_idx, cell)| {
+                        cell.and_then(|c| {
+                            if repeat_spanned || (row_idx == c.row_start && col_idx == c.col_start)
+                            {
+                                Some(c.text.clone())
+                            } else {
+                                None
+                            }
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Serializes the table as delimiter-separated text, quoting fields that
+    /// contain the delimiter, a quote, or a newline (RFC 4180 style).
+    #[pyo3(signature = (delimiter=",", empty=""))]
+    fn to_csv(&self, delimiter: &str, empty: &str) -> String {
+        self.to_grid(false)
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| csv_escape(cell.as_deref().unwrap_or(empty), delimiter))
+                    .collect::<Vec<_>>()
+                    .join(delimiter)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Serializes the table as a GitHub-flavored Markdown table, with a
+    /// header separator row and pipe characters in cell text escaped, in the
+    /// same column/border model used by text-grid renderers like tabled or
+    /// papergrid.
+    #[pyo3(signature = (empty=""))]
+    fn to_markdown(&self, empty: &str) -> String {
+        let grid = self.to_grid(false);
+        let Some(header) = grid.first() else {
+            return String::new();
+        };
+        let mut lines = Vec::with_capacity(grid.len() + 1);
+        lines.push(markdown_row(header, empty));
+        lines.push(format!(
+            "| {} |",
+            vec!["---"; header.len()].join(" | ")
+        ));
+        lines.extend(grid[1..].iter().map(|row| markdown_row(row, empty)));
+        lines.join("\n")
+    }
+
+    /// Renders the table as human-readable, monospaced, column-aligned
+    /// plain text: the max display width of each column is computed in a
+    /// first pass, then every cell is padded to that width in a second
+    /// pass.
+    ///
+    /// `align` gives the per-column alignment ("left", "right", or
+    /// "center"); columns beyond the end of `align` default to "left".
+    /// When `unicode_aware` is `true`, column widths are measured with an
+    /// approximate Unicode display width (wide CJK glyphs count as two
+    /// columns) instead of counting `char`s 1-for-1, so such columns line
+    /// up even when their text isn't pure ASCII.
+    #[pyo3(signature = (separator="  ", empty="", align=None, unicode_aware=false))]
+    fn to_aligned_string(
+        &self,
+        separator: &str,
+        empty: &str,
+        align: Option<Vec<String>>,
+        unicode_aware: bool,
+    ) -> PyResult<String> {
+        let grid = self.to_grid(false);
+        let num_cols = grid.first().map_or(0, |row| row.len());
+
+        let mut aligns = align
+            .unwrap_or_default()
+            .iter()
+            .map(|s| Align::from_str(s))
+            .collect::<Result<Vec<_>, _>>()?;
+        aligns.resize(num_cols, Align::Left);
+
+        let text_width = |s: &str| if unicode_aware { display_width(s) } else { s.chars().count() };
+
+        let mut col_widths = vec![0usize; num_cols];
+        for row in &grid {
+            for (col, cell) in row.iter().enumerate() {
+                let width = text_width(cell.as_deref().unwrap_or(empty));
+                col_widths[col] = col_widths[col].max(width);
+            }
+        }
+
+        Ok(grid
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(col, cell)| {
+                        aligns[col].pad(cell.as_deref().unwrap_or(empty), col_widths[col], text_width)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(separator)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Serializes the table to RON (Rusty Object Notation), preserving cell
+    /// order, spans, and the table's bounding box and page index, so it
+    /// round-trips through [`Table::from_ron`].
+    ///
+    /// When `pretty` is `true` (the default), the output is indented and
+    /// newline-separated for human editing; when `false`, it is emitted as
+    /// a single compact line.
+    #[pyo3(signature = (pretty=true))]
+    fn to_ron(&self, pretty: bool) -> String {
+        write_table_ron(self, pretty)
+    }
+
+    /// Parses a table previously serialized with [`Table::to_ron`], in
+    /// either its pretty or compact form.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyValueError` if `s` isn't well-formed RON in the shape
+    /// `to_ron` produces.
+    #[staticmethod]
+    fn from_ron(s: &str) -> PyResult<Table> {
+        Ok(RonReader::new(s).parse_table()?)
+    }
+
+    /// Computes aggregate statistics over the numeric values in column
+    /// `col`: count, min, max, mean, variance, and standard deviation, plus
+    /// any of `quantiles` (each in `[0, 1]`).
+    ///
+    /// Reads `self.to_grid(false)`, parsing each cell's text in that column
+    /// as an `f64` and skipping cells that are blank or don't parse. `"nan"`/
+    /// `"inf"`/`"-inf"` cells parse successfully under Rust's `f64` grammar
+    /// but aren't real numbers for aggregation purposes, so they're filtered
+    /// out alongside the unparsable ones. Mean and variance are computed
+    /// with Welford's online algorithm and each requested quantile with the
+    /// P² algorithm, so the whole pass uses O(1) memory regardless of row
+    /// count.
+    #[pyo3(signature = (col, quantiles=None))]
+    fn column_stats(&self, col: usize, quantiles: Option<Vec<f64>>) -> ColumnStats {
+        let quantiles = quantiles.unwrap_or_default();
+        let values = self.to_grid(false).into_iter().filter_map(move |row| {
+            row.into_iter()
+                .nth(col)
+                .flatten()
+                .and_then(|text| text.trim().parse::<f64>().ok())
+                .filter(|v| v.is_finite())
+        });
+        compute_column_stats(values, &quantiles)
+    }
+
+    /// Builds a [`StringIndex`] over the string values in column `col`, for
+    /// fast equality and prefix lookups on that column without scanning
+    /// every row. Blank cells are skipped.
+    fn build_string_index(&self, col: usize) -> StringIndex {
+        build_string_index(self, col)
+    }
+}
+
+/// Escapes a single CSV field, quoting it when it contains the delimiter, a
+/// double quote, or a newline.
+fn csv_escape(field: &str, delimiter: &str) -> String {
+    if field.contains(delimiter) || field.contains('"') || field.contains(['\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Formats one Markdown table row, escaping pipe characters in cell text.
+fn markdown_row(row: &[Option<String>], empty: &str) -> String {
+    let cells: Vec<String> = row
+        .iter()
+        .map(|cell| cell.as_deref().unwrap_or(empty).replace('|', "\\|"))
+        .collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Escapes a string for embedding in RON output, matching the escapes
+/// [`RonReader::parse_string`] understands.
+fn ron_escape_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Formats a `BboxKey` as a RON tuple `(x1, y1, x2, y2)`.
+fn write_bbox_ron(bbox: &BboxKey) -> String {
+    format!(
+        "({}, {}, {}, {})",
+        bbox.0.into_inner(),
+        bbox.1.into_inner(),
+        bbox.2.into_inner(),
+        bbox.3.into_inner()
+    )
+}
+
+/// Formats one `TableCell` as a RON named-tuple struct.
+fn write_cell_ron(cell: &TableCell, pretty: bool) -> String {
+    if pretty {
+        format!(
+            "(\n    text: {},\n    bbox: {},\n    col_start: {},\n    colspan: {},\n    row_start: {},\n    rowspan: {},\n)",
+            ron_escape_string(&cell.text),
+            write_bbox_ron(&cell.bbox),
+            cell.col_start,
+            cell.colspan,
+            cell.row_start,
+            cell.rowspan,
+        )
+    } else {
+        format!(
+            "(text: {}, bbox: {}, col_start: {}, colspan: {}, row_start: {}, rowspan: {})",
+            ron_escape_string(&cell.text),
+            write_bbox_ron(&cell.bbox),
+            cell.col_start,
+            cell.colspan,
+            cell.row_start,
+            cell.rowspan,
+        )
+    }
+}
+
+/// Prefixes every line of `s` with `indent`, used to nest a cell's own
+/// multi-line RON under the table's `cells` list.
+fn indent_lines(s: &str, indent: &str) -> String {
+    s.lines()
+        .map(|line| format!("{indent}{line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats a `Table` as a RON named-tuple struct, preserving cell order.
+///
+/// `pub(crate)` (rather than only reachable through [`Table::to_ron`]) so
+/// [`crate::pdfium_pool`] can serialize real extraction results across its
+/// worker IPC pipe instead of a lossy summary.
+pub(crate) fn write_table_ron(table: &Table, pretty: bool) -> String {
+    let cells_ron: Vec<String> = table
+        .cells
+        .iter()
+        .map(|cell| write_cell_ron(cell, pretty))
+        .collect();
+
+    if pretty {
+        let cells_block = if cells_ron.is_empty() {
+            "[]".to_string()
+        } else {
+            let indented: Vec<String> = cells_ron
+                .iter()
+                .map(|c| indent_lines(c, "        "))
+                .collect();
+            format!("[\n{},\n    ]", indented.join(",\n"))
+        };
+        format!(
+            "(\n    page_index: {},\n    text_extracted: {},\n    bbox: {},\n    cells: {},\n)",
+            table.page_index,
+            table.text_extracted,
+            write_bbox_ron(&table.bbox),
+            cells_block
+        )
+    } else {
+        format!(
+            "(page_index: {}, text_extracted: {}, bbox: {}, cells: [{}])",
+            table.page_index,
+            table.text_extracted,
+            write_bbox_ron(&table.bbox),
+            cells_ron.join(", ")
+        )
+    }
+}
+
+/// Error produced by [`Table::from_ron`] when parsing malformed RON text.
+#[derive(Debug, Clone, Error)]
+#[error("invalid RON table at byte {pos}: {message}")]
+struct RonParseError {
+    pos: usize,
+    message: String,
+}
+
+impl From<RonParseError> for PyErr {
+    fn from(err: RonParseError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+type RonResult<T> = Result<T, RonParseError>;
+
+/// A minimal recursive-descent reader for the specific RON shape
+/// [`write_table_ron`] produces (not a general-purpose RON parser).
+struct RonReader<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> RonReader<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn err(&self, message: impl Into<String>) -> RonParseError {
+        RonParseError {
+            pos: self.pos,
+            message: message.into(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(c) = self.rest().chars().next() {
+            if c.is_whitespace() {
+                self.pos += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek_char(&mut self) -> Option<char> {
+        self.skip_ws();
+        self.rest().chars().next()
+    }
+
+    fn expect_char(&mut self, expected: char) -> RonResult<()> {
+        match self.peek_char() {
+            Some(c) if c == expected => {
+                self.pos += c.len_utf8();
+                Ok(())
+            }
+            Some(c) => Err(self.err(format!("expected '{expected}', found '{c}'"))),
+            None => Err(self.err(format!("expected '{expected}', found end of input"))),
+        }
+    }
+
+    fn parse_ident(&mut self) -> RonResult<&'a str> {
+        self.skip_ws();
+        let start = self.pos;
+        while let Some(c) = self.rest().chars().next() {
+            if c.is_alphanumeric() || c == '_' {
+                self.pos += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+        if self.pos == start {
+            return Err(self.err("expected an identifier"));
+        }
+        Ok(&self.input[start..self.pos])
+    }
+
+    fn parse_string(&mut self) -> RonResult<String> {
+        self.expect_char('"')?;
+        let mut out = String::new();
+        loop {
+            match self.rest().chars().next() {
+                None => return Err(self.err("unterminated string")),
+                Some('"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some('\\') => {
+                    self.pos += 1;
+                    match self.rest().chars().next() {
+                        Some('"') => {
+                            out.push('"');
+                            self.pos += 1;
+                        }
+                        Some('\\') => {
+                            out.push('\\');
+                            self.pos += 1;
+                        }
+                        Some('n') => {
+                            out.push('\n');
+                            self.pos += 1;
+                        }
+                        Some('r') => {
+                            out.push('\r');
+                            self.pos += 1;
+                        }
+                        Some(c) => return Err(self.err(format!("unsupported escape '\\{c}'"))),
+                        None => return Err(self.err("unterminated escape")),
+                    }
+                }
+                Some(c) => {
+                    out.push(c);
+                    self.pos += c.len_utf8();
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_number_token(&mut self) -> RonResult<&'a str> {
+        self.skip_ws();
+        let start = self.pos;
+        if self.rest().starts_with('-') {
+            self.pos += 1;
+        }
+        while let Some(c) = self.rest().chars().next() {
+            if c.is_ascii_digit() || c == '.' {
+                self.pos += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+        if self.pos == start {
+            return Err(self.err("expected a number"));
+        }
+        Ok(&self.input[start..self.pos])
+    }
+
+    fn parse_f32(&mut self) -> RonResult<f32> {
+        let tok = self.parse_number_token()?;
+        tok.parse::<f32>()
+            .map_err(|_| self.err(format!("invalid float {tok:?}")))
+    }
+
+    fn parse_usize(&mut self) -> RonResult<usize> {
+        let tok = self.parse_number_token()?;
+        tok.parse::<usize>()
+            .map_err(|_| self.err(format!("invalid integer {tok:?}")))
+    }
+
+    fn parse_bool(&mut self) -> RonResult<bool> {
+        match self.parse_ident()? {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            other => Err(self.err(format!("expected a boolean, found {other:?}"))),
+        }
+    }
+
+    /// Consumes a trailing `,` if present, ahead of a closing delimiter.
+    fn skip_optional_comma(&mut self) {
+        if self.peek_char() == Some(',') {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_bbox(&mut self) -> RonResult<BboxKey> {
+        self.expect_char('(')?;
+        let x1 = self.parse_f32()?;
+        self.expect_char(',')?;
+        let y1 = self.parse_f32()?;
+        self.expect_char(',')?;
+        let x2 = self.parse_f32()?;
+        self.expect_char(',')?;
+        let y2 = self.parse_f32()?;
+        self.skip_optional_comma();
+        self.expect_char(')')?;
+        Ok((
+            OrderedFloat(x1),
+            OrderedFloat(y1),
+            OrderedFloat(x2),
+            OrderedFloat(y2),
+        ))
+    }
+
+    fn parse_table_cell(&mut self) -> RonResult<TableCell> {
+        self.expect_char('(')?;
+        let (mut text, mut bbox, mut col_start, mut colspan, mut row_start, mut rowspan) =
+            (None, None, None, None, None, None);
+        while self.peek_char() != Some(')') {
+            let field = self.parse_ident()?;
+            self.expect_char(':')?;
+            match field {
+                "text" => text = Some(self.parse_string()?),
+                "bbox" => bbox = Some(self.parse_bbox()?),
+                "col_start" => col_start = Some(self.parse_usize()?),
+                "colspan" => colspan = Some(self.parse_usize()?),
+                "row_start" => row_start = Some(self.parse_usize()?),
+                "rowspan" => rowspan = Some(self.parse_usize()?),
+                other => return Err(self.err(format!("unknown table cell field {other:?}"))),
+            }
+            if self.peek_char() == Some(',') {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        self.expect_char(')')?;
+        Ok(TableCell {
+            text: text.ok_or_else(|| self.err("missing field \"text\""))?,
+            bbox: bbox.ok_or_else(|| self.err("missing field \"bbox\""))?,
+            col_start: col_start.ok_or_else(|| self.err("missing field \"col_start\""))?,
+            colspan: colspan.ok_or_else(|| self.err("missing field \"colspan\""))?,
+            row_start: row_start.ok_or_else(|| self.err("missing field \"row_start\""))?,
+            rowspan: rowspan.ok_or_else(|| self.err("missing field \"rowspan\""))?,
+        })
+    }
+
+    fn parse_cells(&mut self) -> RonResult<Vec<TableCell>> {
+        self.expect_char('[')?;
+        let mut cells = Vec::new();
+        while self.peek_char() != Some(']') {
+            cells.push(self.parse_table_cell()?);
+            if self.peek_char() == Some(',') {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        self.expect_char(']')?;
+        Ok(cells)
+    }
+
+    fn parse_table(&mut self) -> RonResult<Table> {
+        self.expect_char('(')?;
+        let (mut page_index, mut text_extracted, mut bbox, mut cells) = (None, None, None, None);
+        while self.peek_char() != Some(')') {
+            let field = self.parse_ident()?;
+            self.expect_char(':')?;
+            match field {
+                "page_index" => page_index = Some(self.parse_usize()?),
+                "text_extracted" => text_extracted = Some(self.parse_bool()?),
+                "bbox" => bbox = Some(self.parse_bbox()?),
+                "cells" => cells = Some(self.parse_cells()?),
+                other => return Err(self.err(format!("unknown table field {other:?}"))),
+            }
+            if self.peek_char() == Some(',') {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        self.expect_char(')')?;
+        if self.peek_char().is_some() {
+            return Err(self.err("unexpected trailing input"));
+        }
+        Ok(Table {
+            cells: cells.ok_or_else(|| self.err("missing field \"cells\""))?,
+            bbox: bbox.ok_or_else(|| self.err("missing field \"bbox\""))?,
+            page_index: page_index.ok_or_else(|| self.err("missing field \"page_index\""))?,
+            text_extracted: text_extracted
+                .ok_or_else(|| self.err("missing field \"text_extracted\""))?,
+        })
+    }
+}
+
+/// Parses a table previously serialized with [`write_table_ron`], returning
+/// a human-readable error message rather than [`RonParseError`] (which is
+/// private to this module) so callers outside `tables` — namely
+/// [`crate::pdfium_pool`]'s worker IPC — don't need to depend on it.
+pub(crate) fn parse_table_ron(s: &str) -> Result<Table, String> {
+    RonReader::new(s).parse_table().map_err(|e| e.to_string())
 }
 
 /// Computes the bounding box of a table from its cell bounding boxes.
@@ -196,6 +889,47 @@ fn get_table_bbox(cells_bbox: &[BboxKey]) -> BboxKey {
 }
 
 impl Table {
+    /// Builds the sorted, de-duplicated list of grid separator coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells_bbox` - Bounding boxes for all cells in the table.
+    /// * `start_axis` - The bbox axis index for the low edge (0=x1, 1=y1).
+    /// * `end_axis` - The bbox axis index for the high edge (2=x2, 3=y2).
+    ///
+    /// # Returns
+    ///
+    /// The distinct separator coordinates along that axis, in ascending order.
+    fn build_separators(
+        cells_bbox: &[BboxKey],
+        start_axis: usize,
+        end_axis: usize,
+    ) -> Vec<OrderedFloat<f32>> {
+        let seps: BTreeSet<OrderedFloat<f32>> = cells_bbox
+            .iter()
+            .flat_map(|bbox| [get_axis_value(bbox, start_axis), get_axis_value(bbox, end_axis)])
+            .collect();
+        seps.into_iter().collect()
+    }
+
+    /// Finds the half-open separator index range `[start, start + span)` a
+    /// cell's edge pair covers along one axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `separators` - The sorted separator coordinates for the axis.
+    /// * `low` - The cell's low edge coordinate on that axis.
+    /// * `high` - The cell's high edge coordinate on that axis.
+    ///
+    /// # Returns
+    ///
+    /// The `(start_index, span)` of separators covered by `[low, high)`.
+    fn span_over(separators: &[OrderedFloat<f32>], low: OrderedFloat<f32>, high: OrderedFloat<f32>) -> (usize, usize) {
+        let start = separators.binary_search(&low).unwrap_or_else(|i| i);
+        let end = separators.binary_search(&high).unwrap_or_else(|i| i);
+        (start, end.saturating_sub(start).max(1))
+    }
+
     /// Creates a new Table from cell bounding boxes.
     ///
     /// # Arguments
@@ -217,12 +951,23 @@ impl Table {
         we_settings: Option<&WordsExtractSettings>,
     ) -> Self {
         let bbox = get_table_bbox(cells_bbox);
-        let cells;
-        cells = cells_bbox
+        let col_separators = Self::build_separators(cells_bbox, 0, 2);
+        let row_separators = Self::build_separators(cells_bbox, 1, 3);
+        let cells = cells_bbox
             .iter()
-            .map(|bbox| TableCell {
-                text: "".to_string(),
-                bbox: *bbox,
+            .map(|bbox| {
+                let (col_start, colspan) =
+                    Self::span_over(&col_separators, bbox.0, bbox.2);
+                let (row_start, rowspan) =
+                    Self::span_over(&row_separators, bbox.1, bbox.3);
+                TableCell {
+                    text: "".to_string(),
+                    bbox: *bbox,
+                    col_start,
+                    colspan,
+                    row_start,
+                    rowspan,
+                }
             })
             .collect();
         let mut slf = Self {
@@ -242,6 +987,11 @@ impl Table {
 
     /// Gets all rows or columns from the table cells.
     ///
+    /// Cells are placed by their stored `row_start`/`rowspan` and
+    /// `col_start`/`colspan`, so a cell that spans several rows or columns
+    /// (because an interior rule is absent) appears, via a shared reference,
+    /// in every group index it covers rather than just one.
+    ///
     /// # Arguments
     ///
     /// * `cells` - The table cells.
@@ -254,62 +1004,49 @@ impl Table {
         cells: &'tab [TableCell],
         kind: CellGroupKind,
     ) -> Vec<CellGroup<'tab>> {
-        let axis: usize = if kind == CellGroupKind::Row { 0 } else { 1 };
-        let antiaxis: usize = if axis == 0 { 1 } else { 0 };
-
-        let mut indices: Vec<usize> = (0..cells.len()).collect();
-        indices.sort_by(|&a, &b| {
-            let cell_a = &cells[a];
-            let cell_b = &cells[b];
-            let a_anti = get_axis_value(&cell_a.bbox, antiaxis);
-            let b_anti = get_axis_value(&cell_b.bbox, antiaxis);
-            let a_axis = get_axis_value(&cell_a.bbox, axis);
-            let b_axis = get_axis_value(&cell_b.bbox, axis);
-
-            a_anti
-                .partial_cmp(&b_anti)
-                .unwrap_or(std::cmp::Ordering::Equal)
-                .then(
-                    a_axis
-                        .partial_cmp(&b_axis)
-                        .unwrap_or(std::cmp::Ordering::Equal),
-                )
-        });
-
-        let sorted_refs: Vec<&'tab TableCell> = indices.iter().map(|&i| &cells[i]).collect();
+        let (group_start, group_span, slot_start, slot_span): (
+            fn(&TableCell) -> usize,
+            fn(&TableCell) -> usize,
+            fn(&TableCell) -> usize,
+            fn(&TableCell) -> usize,
+        ) = match kind {
+            CellGroupKind::Row => (
+                |c| c.row_start,
+                |c| c.rowspan,
+                |c| c.col_start,
+                |c| c.colspan,
+            ),
+            CellGroupKind::Column => (
+                |c| c.col_start,
+                |c| c.colspan,
+                |c| c.row_start,
+                |c| c.rowspan,
+            ),
+        };
 
-        let xs: BTreeSet<OrderedFloat<f32>> = cells
+        let num_groups = cells
             .iter()
-            .map(|cell| get_axis_value(&cell.bbox, axis))
-            .collect();
-        let xs: Vec<OrderedFloat<f32>> = xs.into_iter().collect();
-
-        let mut grouped: HashMap<OrderedFloat<f32>, Vec<&TableCell>> = HashMap::new();
-        for cell in &sorted_refs {
-            let key = get_axis_value(&cell.bbox, antiaxis);
-            grouped.entry(key).or_default().push(cell);
-        }
-
-        let mut group_keys: Vec<OrderedFloat<f32>> = grouped.keys().copied().collect();
-        group_keys.sort();
-
-        let mut rows: Vec<CellGroup> = Vec::new();
-
-        for group in sorted_refs.chunk_by(|a, b| {
-            (get_axis_value(&a.bbox, antiaxis) - get_axis_value(&b.bbox, antiaxis)).abs() < 0.001
-        }) {
-            let xdict: HashMap<OrderedFloat<f32>, &'tab TableCell> = group
-                .iter()
-                .map(|cell| (get_axis_value(&cell.bbox, axis), *cell))
-                .collect();
+            .map(|c| group_start(c) + group_span(c))
+            .max()
+            .unwrap_or(0);
+        let num_slots = cells
+            .iter()
+            .map(|c| slot_start(c) + slot_span(c))
+            .max()
+            .unwrap_or(0);
 
-            let row_data: Vec<Option<&'tab TableCell>> =
-                xs.iter().map(|x| xdict.get(&x).copied()).collect();
+        let mut grid: Vec<Vec<Option<&'tab TableCell>>> =
+            (0..num_groups).map(|_| vec![None; num_slots]).collect();
 
-            rows.push(CellGroup::new(row_data));
+        for cell in cells {
+            for g in group_start(cell)..group_start(cell) + group_span(cell) {
+                for s in slot_start(cell)..slot_start(cell) + slot_span(cell) {
+                    grid[g][s] = Some(cell);
+                }
+            }
         }
 
-        rows
+        grid.into_iter().map(CellGroup::new).collect()
     }
 
     /// Returns all rows in the table.
@@ -340,6 +1077,20 @@ impl Table {
         h_mid >= x1 && h_mid < x2 && v_mid >= y1 && v_mid < y2
     }
 
+    /// Checks if a character overlaps a cell's bbox by at least
+    /// `overlap_ratio` of the character's own area.
+    ///
+    /// # Arguments
+    ///
+    /// * `char` - The character to check.
+    /// * `bbox` - The cell's bounding box.
+    /// * `overlap_ratio` - The minimum fraction of the character's own bbox
+    ///   area that must fall inside `bbox`.
+    #[inline]
+    fn char_overlaps_cell(char: &Char, bbox: &BboxKey, overlap_ratio: f32) -> bool {
+        bbox_intersection_over(*bbox, char.bbox) >= overlap_ratio
+    }
+
     /// Extracts text content for all cells in the table.
     ///
     /// # Arguments
@@ -354,11 +1105,12 @@ impl Table {
             ..base_settings.clone()
         };
         let word_extractor = WordExtractor::new(&word_settings);
+        let overlap_ratio = word_settings.cell_overlap_ratio.into_inner();
 
         for cell in &mut self.cells {
             let cell_chars: Vec<Char> = chars
                 .iter()
-                .filter(|char| Self::char_in_bbox(char, &cell.bbox))
+                .filter(|char| Self::char_overlaps_cell(char, &cell.bbox, overlap_ratio))
                 .cloned()
                 .collect();
 
@@ -402,6 +1154,17 @@ fn filter_edges_by_min_len(edges: &mut Vec<Edge>, min_len: OrderedFloat<f32>) {
 /// # Returns
 ///
 /// A HashMap mapping intersection points to the edges that meet there.
+/// Ordering of sweep events that share the same x coordinate: a horizontal
+/// edge must become active (and inactive) on either side of any vertical
+/// query landing on its boundary, so activations sort before queries, which
+/// sort before deactivations.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum SweepEventKind {
+    Activate,
+    Query,
+    Deactivate,
+}
+
 fn edges_to_intersections(
     edges: &mut HashMap<Orientation, Vec<Edge>>,
     intersection_x_tolerance: OrderedFloat<f32>,
@@ -421,24 +1184,58 @@ fn edges_to_intersections(
     let v_edges = edges.get(&Orientation::Vertical).unwrap();
     let h_edges = edges.get(&Orientation::Horizontal).unwrap();
 
-    for v in v_edges.iter() {
-        for h in h_edges.iter() {
-            if v.y1 <= h.y1 + intersection_y_tolerance
-                && v.y2 >= h.y1 - intersection_y_tolerance
-                && v.x1 >= h.x1 - intersection_x_tolerance
-                && v.x1 <= h.x2 + intersection_x_tolerance
-            {
-                let vertex = (v.x1, h.y1);
-
-                let intersection = intersections.entry(vertex).or_default();
-                intersection
-                    .entry(Orientation::Vertical)
-                    .or_default()
-                    .push((*v).clone());
-                intersection
-                    .entry(Orientation::Horizontal)
-                    .or_default()
-                    .push((*v).clone());
+    // x-ordered sweep: each horizontal edge contributes an activate/deactivate
+    // pair inflated by the x-tolerance, each vertical edge a single query at
+    // its x1. Active horizontals live in a BTreeMap keyed by y1 so a query
+    // only range-scans the y-window it needs, instead of scanning every h.
+    let mut events: Vec<(OrderedFloat<f32>, SweepEventKind, usize)> =
+        Vec::with_capacity(h_edges.len() * 2 + v_edges.len());
+    for (i, h) in h_edges.iter().enumerate() {
+        events.push((h.x1 - intersection_x_tolerance, SweepEventKind::Activate, i));
+        events.push((h.x2 + intersection_x_tolerance, SweepEventKind::Deactivate, i));
+    }
+    for (j, v) in v_edges.iter().enumerate() {
+        events.push((v.x1, SweepEventKind::Query, j));
+    }
+    events.sort_by_key(|&(x, kind, _)| (x, kind));
+
+    let mut active: BTreeMap<OrderedFloat<f32>, Vec<usize>> = BTreeMap::new();
+
+    for (_, kind, idx) in events {
+        match kind {
+            SweepEventKind::Activate => {
+                active.entry(h_edges[idx].y1).or_default().push(idx);
+            }
+            SweepEventKind::Deactivate => {
+                if let Some(bucket) = active.get_mut(&h_edges[idx].y1) {
+                    if let Some(pos) = bucket.iter().position(|&i| i == idx) {
+                        bucket.swap_remove(pos);
+                    }
+                    if bucket.is_empty() {
+                        active.remove(&h_edges[idx].y1);
+                    }
+                }
+            }
+            SweepEventKind::Query => {
+                let v = &v_edges[idx];
+                let lo = v.y1 - intersection_y_tolerance;
+                let hi = v.y2 + intersection_y_tolerance;
+                for (_, bucket) in active.range(lo..=hi) {
+                    for &h_idx in bucket {
+                        let h = &h_edges[h_idx];
+                        let vertex = (v.x1, h.y1);
+
+                        let intersection = intersections.entry(vertex).or_default();
+                        intersection
+                            .entry(Orientation::Vertical)
+                            .or_default()
+                            .push((*v).clone());
+                        intersection
+                            .entry(Orientation::Horizontal)
+                            .or_default()
+                            .push(h.clone());
+                    }
+                }
             }
         }
     }
@@ -649,8 +1446,108 @@ impl TableFinder {
         }
     }
 
+    /// Converts a plain (x1, y1, x2, y2) tuple into a BboxKey.
+    fn to_bbox_key((x1, y1, x2, y2): (f32, f32, f32, f32)) -> BboxKey {
+        (
+            OrderedFloat(x1),
+            OrderedFloat(y1),
+            OrderedFloat(x2),
+            OrderedFloat(y2),
+        )
+    }
+
+    /// Restricts a page's objects to those overlapping a clip region.
+    ///
+    /// # Arguments
+    ///
+    /// * `objects` - The page objects to restrict.
+    /// * `clip_bbox` - The region to keep objects within.
+    ///
+    /// # Returns
+    ///
+    /// An `Objects` containing only the rects, lines, and chars that overlap
+    /// `clip_bbox`.
+    fn clip_objects(objects: &Objects, clip_bbox: BboxKey) -> Objects {
+        Objects {
+            rects: objects
+                .rects
+                .iter()
+                .filter(|r| get_bbox_overlap(&r.bbox, &clip_bbox))
+                .cloned()
+                .collect(),
+            lines: objects
+                .lines
+                .iter()
+                .filter(|l| {
+                    let xs = l.points.iter().map(|p| p.0);
+                    let ys = l.points.iter().map(|p| p.1);
+                    let min_x = xs.clone().fold(OrderedFloat(f32::INFINITY), cmp::min);
+                    let max_x = xs.fold(OrderedFloat(f32::NEG_INFINITY), cmp::max);
+                    let min_y = ys.clone().fold(OrderedFloat(f32::INFINITY), cmp::min);
+                    let max_y = ys.fold(OrderedFloat(f32::NEG_INFINITY), cmp::max);
+                    get_bbox_overlap(&(min_x, min_y, max_x, max_y), &clip_bbox)
+                })
+                .cloned()
+                .collect(),
+            chars: objects
+                .chars
+                .iter()
+                .filter(|c| get_bbox_overlap(&c.bbox, &clip_bbox))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Converts an explicit line into a concrete edge spanning `span`.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The caller-supplied coordinate or full edge.
+    /// * `orientation` - Whether the resulting edge is vertical or horizontal.
+    /// * `span` - The (low, high) extent the edge should stretch across when
+    ///   `line` is a bare coordinate; ignored for `ExplicitLine::Full`.
+    ///
+    /// # Returns
+    ///
+    /// The concrete `Edge` to inject into the detected edge set.
+    fn explicit_line_to_edge(line: &ExplicitLine, orientation: Orientation, span: (f32, f32)) -> Edge {
+        match line {
+            ExplicitLine::Coordinate(value) => {
+                let value = value.as_ordered_float();
+                let (lo, hi) = (OrderedFloat(span.0), OrderedFloat(span.1));
+                match orientation {
+                    Orientation::Vertical => Edge {
+                        orientation,
+                        x1: value,
+                        x2: value,
+                        y1: lo,
+                        y2: hi,
+                        width: OrderedFloat(1.0),
+                        color: PdfColor::new(0, 0, 0, 255),
+                    },
+                    Orientation::Horizontal => Edge {
+                        orientation,
+                        x1: lo,
+                        x2: hi,
+                        y1: value,
+                        y2: value,
+                        width: OrderedFloat(1.0),
+                        color: PdfColor::new(0, 0, 0, 255),
+                    },
+                }
+            }
+            ExplicitLine::Full(edge) => edge.clone(),
+        }
+    }
+
     /// Extracts and processes edges from a PDF page.
     ///
+    /// Detected edges are restricted to `settings.clip_bbox` when set, then,
+    /// for each axis whose strategy includes `StrategyType::Explicit`,
+    /// augmented with `settings.explicit_vertical_lines` and
+    /// `settings.explicit_horizontal_lines` before merging, so explicit edges
+    /// participate in intersection detection exactly like detected ones.
+    ///
     /// # Arguments
     ///
     /// * `page` - The PDF page to extract edges from.
@@ -666,7 +1563,39 @@ impl TableFinder {
             page.extract_objects();
         }
         let objects = objects_opt.as_ref().expect("Objects should be extracted");
-        let mut edges_all = make_edges(objects, self.settings.clone());
+
+        let clip_bbox = settings.clip_bbox.map(Self::to_bbox_key);
+        let clipped_objects;
+        let objects_for_edges = match clip_bbox {
+            Some(bbox) => {
+                clipped_objects = Self::clip_objects(objects, bbox);
+                &clipped_objects
+            }
+            None => objects,
+        };
+        let mut edges_all = make_edges(objects_for_edges, self.settings.clone());
+
+        let (x_span, y_span) = match settings.clip_bbox {
+            Some((x1, y1, x2, y2)) => ((x1, x2), (y1, y2)),
+            None => ((0.0, page.width()), (0.0, page.height())),
+        };
+        if settings.vertical_strategy.contains(StrategyType::Explicit) {
+            edges_all.entry(Orientation::Vertical).or_default().extend(
+                settings
+                    .explicit_vertical_lines
+                    .iter()
+                    .map(|line| Self::explicit_line_to_edge(line, Orientation::Vertical, y_span)),
+            );
+        }
+        if settings.horizontal_strategy.contains(StrategyType::Explicit) {
+            edges_all
+                .entry(Orientation::Horizontal)
+                .or_default()
+                .extend(settings.explicit_horizontal_lines.iter().map(|line| {
+                    Self::explicit_line_to_edge(line, Orientation::Horizontal, x_span)
+                }));
+        }
+
         let mut v_edges = edges_all.remove(&Orientation::Vertical).unwrap_or_default();
         filter_edges_by_min_len(&mut v_edges, settings.edge_min_length_prefilter);
         let mut h_edges = edges_all
@@ -684,6 +1613,9 @@ impl TableFinder {
             settings.snap_y_tolerance,
             settings.join_x_tolerance,
             settings.join_y_tolerance,
+            settings.dash_max_gap,
+            settings.min_dash_count,
+            settings.respect_edge_style,
         );
         if let Some(h_edges) = edges_merged.get_mut(&Orientation::Horizontal) {
             filter_edges_by_min_len(h_edges, settings.edge_min_length);
@@ -772,10 +1704,597 @@ pub fn find_tables_from_cells(
 /// A vector of Table objects found in the page.
 pub fn find_tables(pdf_page: &Page, tf_settings: Rc<TfSettings>, extract_text: bool) -> Vec<Table> {
     let cells = find_all_cells_bboxes(pdf_page, tf_settings.clone());
-    find_tables_from_cells(
+    let mut tables = find_tables_from_cells(
         &cells,
         extract_text,
         Some(pdf_page),
         Some(&tf_settings.text_settings),
-    )
+    );
+
+    // A clip_bbox restricts text assignment to its region too, so characters
+    // outside it are re-excluded even if a cell's bbox reaches past the clip.
+    if extract_text {
+        if let Some(clip_bbox) = tf_settings.clip_bbox {
+            let clip_bbox = TableFinder::to_bbox_key(clip_bbox);
+            if pdf_page.objects.borrow().is_none() {
+                pdf_page.extract_objects();
+            }
+            let objects = pdf_page.objects.borrow();
+            let clipped_chars: Vec<Char> = objects
+                .as_ref()
+                .expect("Objects should be extracted")
+                .chars
+                .iter()
+                .filter(|c| Table::char_in_bbox(c, &clip_bbox))
+                .cloned()
+                .collect();
+            for table in &mut tables {
+                table.extract_text(&clipped_chars, Some(&tf_settings.text_settings));
+            }
+        }
+    }
+
+    tables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_line_to_edge_coordinate_spans_full_extent() {
+        let coord = NonNegativeF32::new(50.0, "explicit_vertical_lines").unwrap();
+        let line = ExplicitLine::Coordinate(coord);
+
+        let edge = TableFinder::explicit_line_to_edge(&line, Orientation::Vertical, (0.0, 792.0));
+        assert_eq!(edge.orientation, Orientation::Vertical);
+        assert_eq!(edge.x1, OrderedFloat(50.0));
+        assert_eq!(edge.x2, OrderedFloat(50.0));
+        assert_eq!(edge.y1, OrderedFloat(0.0));
+        assert_eq!(edge.y2, OrderedFloat(792.0));
+
+        let edge =
+            TableFinder::explicit_line_to_edge(&line, Orientation::Horizontal, (0.0, 612.0));
+        assert_eq!(edge.orientation, Orientation::Horizontal);
+        assert_eq!(edge.y1, OrderedFloat(50.0));
+        assert_eq!(edge.y2, OrderedFloat(50.0));
+        assert_eq!(edge.x1, OrderedFloat(0.0));
+        assert_eq!(edge.x2, OrderedFloat(612.0));
+    }
+
+    #[test]
+    fn test_explicit_line_to_edge_full_ignores_span() {
+        let full_edge = Edge {
+            orientation: Orientation::Vertical,
+            x1: OrderedFloat(10.0),
+            y1: OrderedFloat(20.0),
+            x2: OrderedFloat(10.0),
+            y2: OrderedFloat(100.0),
+            width: OrderedFloat(2.0),
+            color: PdfColor::new(255, 0, 0, 255),
+        };
+        let line = ExplicitLine::Full(full_edge.clone());
+
+        let edge = TableFinder::explicit_line_to_edge(&line, Orientation::Vertical, (0.0, 9999.0));
+        assert_eq!(edge.y1, full_edge.y1);
+        assert_eq!(edge.y2, full_edge.y2);
+        assert_eq!(edge.width, full_edge.width);
+    }
+
+    fn make_test_table() -> Table {
+        Table {
+            cells: vec![
+                TableCell {
+                    text: "a\"b".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 2,
+                },
+                TableCell {
+                    text: "c".to_string(),
+                    bbox: (
+                        OrderedFloat(10.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(20.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 1,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 1,
+                },
+            ],
+            bbox: (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(20.0),
+                OrderedFloat(5.0),
+            ),
+            page_index: 3,
+            text_extracted: true,
+        }
+    }
+
+    #[test]
+    fn test_table_ron_round_trips_compact() {
+        let table = make_test_table();
+
+        let ron = table.to_ron(false);
+        let parsed = Table::from_ron(&ron).unwrap();
+
+        assert_eq!(parsed.page_index, table.page_index);
+        assert_eq!(parsed.text_extracted, table.text_extracted);
+        assert_eq!(parsed.bbox, table.bbox);
+        assert_eq!(parsed.cells.len(), table.cells.len());
+        assert_eq!(parsed.cells[0].text, "a\"b");
+        assert_eq!(parsed.cells[0].rowspan, 2);
+        assert_eq!(parsed.cells[1].text, "c");
+        assert_eq!(parsed.cells[1].col_start, 1);
+    }
+
+    #[test]
+    fn test_table_ron_round_trips_pretty() {
+        let table = make_test_table();
+
+        let ron = table.to_ron(true);
+        let parsed = Table::from_ron(&ron).unwrap();
+
+        assert_eq!(parsed.bbox, table.bbox);
+        assert_eq!(parsed.cells.len(), table.cells.len());
+        assert_eq!(parsed.cells[0].text, "a\"b");
+    }
+
+    #[test]
+    fn test_table_ron_round_trips_empty_cells() {
+        let table = Table {
+            cells: vec![],
+            bbox: (
+                OrderedFloat(1.0),
+                OrderedFloat(2.0),
+                OrderedFloat(3.0),
+                OrderedFloat(4.0),
+            ),
+            page_index: 0,
+            text_extracted: false,
+        };
+
+        let parsed = Table::from_ron(&table.to_ron(true)).unwrap();
+        assert!(parsed.cells.is_empty());
+        assert_eq!(parsed.bbox, table.bbox);
+    }
+
+    #[test]
+    fn test_table_from_ron_rejects_malformed_input() {
+        assert!(Table::from_ron("not ron").is_err());
+    }
+
+    #[test]
+    fn test_build_separators_dedupes_and_sorts() {
+        let bboxes = vec![
+            (
+                OrderedFloat(10.0),
+                OrderedFloat(0.0),
+                OrderedFloat(20.0),
+                OrderedFloat(5.0),
+            ),
+            (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(5.0),
+            ),
+        ];
+
+        let col_separators = Table::build_separators(&bboxes, 0, 2);
+        assert_eq!(
+            col_separators,
+            vec![OrderedFloat(0.0), OrderedFloat(10.0), OrderedFloat(20.0)]
+        );
+    }
+
+    #[test]
+    fn test_span_over_single_cell() {
+        let separators = vec![OrderedFloat(0.0), OrderedFloat(10.0), OrderedFloat(20.0)];
+
+        assert_eq!(
+            Table::span_over(&separators, OrderedFloat(0.0), OrderedFloat(10.0)),
+            (0, 1)
+        );
+        assert_eq!(
+            Table::span_over(&separators, OrderedFloat(10.0), OrderedFloat(20.0)),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn test_span_over_spanning_cell_covers_multiple_separators() {
+        // A cell whose edges are the outer two separators (no interior rule
+        // at the middle one) should span both slots, not just one.
+        let separators = vec![OrderedFloat(0.0), OrderedFloat(10.0), OrderedFloat(20.0)];
+
+        assert_eq!(
+            Table::span_over(&separators, OrderedFloat(0.0), OrderedFloat(20.0)),
+            (0, 2)
+        );
+    }
+
+    #[test]
+    fn test_span_over_degenerate_zero_width_bbox() {
+        let separators = vec![OrderedFloat(0.0), OrderedFloat(10.0)];
+
+        assert_eq!(
+            Table::span_over(&separators, OrderedFloat(5.0), OrderedFloat(5.0)),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn test_table_new_computes_colspan_for_missing_interior_separator() {
+        // A header cell spanning both columns of a 2-column, 1-row body.
+        let cells_bbox = vec![
+            (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(20.0),
+                OrderedFloat(5.0),
+            ),
+            (
+                OrderedFloat(0.0),
+                OrderedFloat(5.0),
+                OrderedFloat(10.0),
+                OrderedFloat(10.0),
+            ),
+            (
+                OrderedFloat(10.0),
+                OrderedFloat(5.0),
+                OrderedFloat(20.0),
+                OrderedFloat(10.0),
+            ),
+        ];
+
+        let table = Table::new(0, &cells_bbox, false, None, None);
+        let header = table
+            .cells
+            .iter()
+            .find(|c| c.row_start == 0)
+            .expect("header cell");
+        assert_eq!(header.col_start, 0);
+        assert_eq!(header.colspan, 2);
+        assert_eq!(header.row_start, 0);
+        assert_eq!(header.rowspan, 1);
+    }
+
+    #[test]
+    fn test_get_rows_or_cols_places_spanning_cell_at_every_row_it_covers() {
+        let table = make_test_table();
+        // The first cell in make_test_table spans rows 0 and 1 (rowspan 2)
+        // in column 0; the second cell occupies only row 0, column 1.
+        let rows = table.rows();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].cells[0].is_some());
+        assert!(rows[1].cells[0].is_some());
+        assert_eq!(
+            rows[0].cells[0].unwrap() as *const TableCell,
+            rows[1].cells[0].unwrap() as *const TableCell
+        );
+        assert!(rows[0].cells[1].is_some());
+        assert!(rows[1].cells[1].is_none());
+    }
+
+    fn make_2x2_table() -> Table {
+        Table {
+            cells: vec![
+                TableCell {
+                    text: "a".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "bb".to_string(),
+                    bbox: (
+                        OrderedFloat(10.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(20.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 1,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "ccc".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(5.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(10.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 1,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "d".to_string(),
+                    bbox: (
+                        OrderedFloat(10.0),
+                        OrderedFloat(5.0),
+                        OrderedFloat(20.0),
+                        OrderedFloat(10.0),
+                    ),
+                    col_start: 1,
+                    colspan: 1,
+                    row_start: 1,
+                    rowspan: 1,
+                },
+            ],
+            bbox: (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(20.0),
+                OrderedFloat(10.0),
+            ),
+            page_index: 0,
+            text_extracted: true,
+        }
+    }
+
+    #[test]
+    fn test_to_aligned_string_left_aligns_and_pads_columns() {
+        let table = make_2x2_table();
+
+        let out = table.to_aligned_string("  ", "", None, false).unwrap();
+        assert_eq!(out, "a    bb\nccc  d ");
+    }
+
+    #[test]
+    fn test_to_aligned_string_right_align() {
+        let table = make_2x2_table();
+
+        let out = table
+            .to_aligned_string("  ", "", Some(vec!["right".to_string(), "right".to_string()]), false)
+            .unwrap();
+        assert_eq!(out, "  a  bb\nccc   d");
+    }
+
+    #[test]
+    fn test_to_aligned_string_center_align_splits_even_padding() {
+        let table = make_2x2_table();
+
+        // Column 0's widest cell is "ccc" (width 3); "a" needs 2 padding
+        // columns split 1 left / 1 right under center alignment.
+        let out = table
+            .to_aligned_string("|", "", Some(vec!["center".to_string()]), false)
+            .unwrap();
+        let first_line = out.lines().next().unwrap();
+        assert_eq!(first_line, " a |bb");
+    }
+
+    #[test]
+    fn test_to_aligned_string_rejects_invalid_align() {
+        let table = make_2x2_table();
+        assert!(table
+            .to_aligned_string("  ", "", Some(vec!["diagonal".to_string()]), false)
+            .is_err());
+    }
+
+    #[test]
+    fn test_to_aligned_string_unicode_aware_widens_cjk_columns() {
+        let table = Table {
+            cells: vec![
+                TableCell {
+                    text: "\u{4e2d}\u{6587}".to_string(), // "中文", 2 wide chars
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "ab".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(5.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(10.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 1,
+                    rowspan: 1,
+                },
+            ],
+            bbox: (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(10.0),
+            ),
+            page_index: 0,
+            text_extracted: true,
+        };
+
+        // Under char-count width, both cells are width 2 ("中文" and "ab"),
+        // so no padding is added. Under Unicode-aware width, "中文" is 4
+        // display columns (2 per wide char), so "ab" needs 2 trailing spaces
+        // to line up.
+        let plain = table.to_aligned_string("|", "", None, false).unwrap();
+        assert_eq!(plain, "\u{4e2d}\u{6587}\nab");
+
+        let unicode_aware = table.to_aligned_string("|", "", None, true).unwrap();
+        assert_eq!(unicode_aware, "\u{4e2d}\u{6587}\nab  ");
+    }
+
+    #[test]
+    fn test_display_width_treats_wide_chars_as_two_columns() {
+        assert_eq!(display_width("ab"), 2);
+        assert_eq!(display_width("\u{4e2d}\u{6587}"), 4);
+    }
+
+    #[test]
+    fn test_char_display_width_combining_mark_is_zero_width() {
+        assert_eq!(char_display_width('a'), 1);
+        assert_eq!(char_display_width('\u{4e2d}'), 2);
+        assert_eq!(char_display_width('\u{0301}'), 0);
+    }
+
+    #[test]
+    fn test_column_stats_skips_unparsable_cells() {
+        let table = Table {
+            cells: vec![
+                TableCell {
+                    text: "1".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "n/a".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(5.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(10.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 1,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "3".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(15.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 2,
+                    rowspan: 1,
+                },
+            ],
+            bbox: (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(15.0),
+            ),
+            page_index: 0,
+            text_extracted: true,
+        };
+
+        let stats = table.column_stats(0, Some(vec![0.5]));
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.min, Some(1.0));
+        assert_eq!(stats.max, Some(3.0));
+        assert_eq!(stats.mean, Some(2.0));
+    }
+
+    #[test]
+    fn test_column_stats_skips_non_finite_literals() {
+        let table = Table {
+            cells: vec![
+                TableCell {
+                    text: "1".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(0.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(5.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 0,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "NaN".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(5.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(10.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 1,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "inf".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(15.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 2,
+                    rowspan: 1,
+                },
+                TableCell {
+                    text: "3".to_string(),
+                    bbox: (
+                        OrderedFloat(0.0),
+                        OrderedFloat(15.0),
+                        OrderedFloat(10.0),
+                        OrderedFloat(20.0),
+                    ),
+                    col_start: 0,
+                    colspan: 1,
+                    row_start: 3,
+                    rowspan: 1,
+                },
+            ],
+            bbox: (
+                OrderedFloat(0.0),
+                OrderedFloat(0.0),
+                OrderedFloat(10.0),
+                OrderedFloat(20.0),
+            ),
+            page_index: 0,
+            text_extracted: true,
+        };
+
+        // "NaN" and "inf" both parse successfully as f64 but must not reach
+        // the P2 quantile tracker, which panics on a non-finite sample
+        // during its initial-sample sort (`partial_cmp` returns `None` for
+        // NaN, and `inf` would otherwise skew min/max/mean).
+        let stats = table.column_stats(0, Some(vec![0.5]));
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.min, Some(1.0));
+        assert_eq!(stats.max, Some(3.0));
+        assert_eq!(stats.mean, Some(2.0));
+    }
 }
@@ -1,10 +1,11 @@
-use crate::clusters::cluster_objects;
+use crate::clusters::{cluster_objects, ClusterMode};
 use crate::objects::*;
 use crate::settings::*;
-use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 use std::sync::LazyLock;
+use unicode_normalization::UnicodeNormalization;
 
 /// Mapping of Unicode ligature characters to their expanded forms.
 static LIGATURES: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
@@ -25,6 +26,35 @@ static LIGATURES: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(
 static PUNCTUATIONS: LazyLock<HashSet<char>> =
     LazyLock::new(|| "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~".chars().collect());
 
+/// Case- and ligature-folds `text` for approximate matching, expanding
+/// ligatures via the same [`LIGATURES`] map [`WordExtractor`] uses so that
+/// e.g. "ﬁle" folds the same as "file".
+pub(crate) fn fold_for_search(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut buf = [0u8; 4];
+    for ch in text.chars() {
+        let s = ch.encode_utf8(&mut buf);
+        if let Some(expanded) = LIGATURES.get(s as &str) {
+            result.push_str(expanded);
+        } else {
+            result.extend(ch.to_lowercase());
+        }
+    }
+    result
+}
+
+/// Reads the value of a `WordsExtractSettings::extra_attrs` attribute name
+/// off `char`, formatted for equality comparison. Recognized names are
+/// `"fontname"`, `"size"`, and `"upright"`; anything else returns `None`.
+fn char_attr_value(char: &Char, name: &str) -> Option<String> {
+    match name {
+        "fontname" => Some(char.font_name.clone().unwrap_or_default()),
+        "size" => Some(char.font_size.into_inner().to_string()),
+        "upright" => Some(char.upright.to_string()),
+        _ => None,
+    }
+}
+
 /// Represents a word extracted from PDF text.
 ///
 /// A word is a sequence of characters grouped by proximity and alignment.
@@ -37,6 +67,10 @@ pub(crate) struct Word {
     /// The rotation of the word in degrees.
     #[allow(dead_code)]
     pub rotation_degrees: OrderedFloat<f32>,
+    /// Values of `WordsExtractSettings::extra_attrs`, in the same order,
+    /// read off the word's first character.
+    #[allow(dead_code)]
+    pub extra_attrs: Vec<(String, String)>,
 }
 
 impl HasBbox for Word {
@@ -54,16 +88,33 @@ pub(crate) struct WordExtractor {
     x_tolerance: OrderedFloat<f32>,
     /// Y-axis tolerance for line grouping.
     y_tolerance: OrderedFloat<f32>,
+    /// When set, overrides `x_tolerance` with `ratio * char_size` for each
+    /// comparison, where `char_size` is the font size of the trailing
+    /// character of the word built so far.
+    x_tolerance_ratio: Option<OrderedFloat<f32>>,
+    /// When set, overrides `y_tolerance` with `ratio * char_size`, as
+    /// `x_tolerance_ratio` does for the x axis.
+    y_tolerance_ratio: Option<OrderedFloat<f32>>,
     /// Whether to preserve whitespace characters.
     keep_blank_chars: bool,
     /// Whether to use PDF text flow order.
     use_text_flow: bool,
     /// Whether text reads in clockwise direction.
     text_read_in_clockwise: bool,
-    /// Characters that trigger word splits.
-    split_at_punctuation: HashSet<char>,
+    /// Predicate deciding whether a character triggers a word split. The
+    /// `SplitPunctuation` presets in `WordsExtractSettings` desugar to one
+    /// of these; [`WordExtractor::with_split_predicate`] lets Rust callers
+    /// supply their own.
+    split_predicate: Rc<dyn Fn(char) -> bool>,
     /// Ligature expansion mappings.
     expansions: HashMap<&'static str, &'static str>,
+    /// Whether to NFKC-normalize merged word text, which also folds
+    /// trailing combining diacritical marks into their base grapheme.
+    normalize_unicode: bool,
+    /// Character attributes that must be equal between two adjacent
+    /// characters for them to merge into the same word. See
+    /// `WordsExtractSettings::extra_attrs`.
+    extra_attrs: Vec<String>,
 }
 
 impl WordExtractor {
@@ -77,26 +128,51 @@ impl WordExtractor {
     ///
     /// A new WordExtractor instance.
     pub fn new(word_extract_settings: &WordsExtractSettings) -> Self {
-        let split_chars = match &word_extract_settings.split_at_punctuation {
-            Some(SplitPunctuation::All) => PUNCTUATIONS.clone(),
-            Some(SplitPunctuation::Custom(chars)) => chars.chars().collect(),
-            None => HashSet::new(),
-        };
+        let split_predicate: Rc<dyn Fn(char) -> bool> =
+            match &word_extract_settings.split_at_punctuation {
+                Some(SplitPunctuation::All) => Rc::new(|c: char| PUNCTUATIONS.contains(&c)),
+                Some(SplitPunctuation::Custom(chars)) => {
+                    let chars: HashSet<char> = chars.chars().collect();
+                    Rc::new(move |c: char| chars.contains(&c))
+                }
+                None => Rc::new(|_: char| false),
+            };
 
         Self {
             x_tolerance: *word_extract_settings.x_tolerance,
             y_tolerance: *word_extract_settings.y_tolerance,
+            x_tolerance_ratio: word_extract_settings.x_tolerance_ratio.map(|v| *v),
+            y_tolerance_ratio: word_extract_settings.y_tolerance_ratio.map(|v| *v),
             keep_blank_chars: word_extract_settings.keep_blank_chars,
             use_text_flow: word_extract_settings.use_text_flow,
             text_read_in_clockwise: word_extract_settings.text_read_in_clockwise,
-            split_at_punctuation: split_chars,
+            split_predicate,
             expansions: if word_extract_settings.expand_ligatures {
                 LIGATURES.clone()
             } else {
                 HashMap::new()
             },
+            normalize_unicode: word_extract_settings.normalize_unicode,
+            extra_attrs: word_extract_settings.extra_attrs.clone(),
         }
     }
+
+    /// Overrides the character-split predicate with a custom Rust closure,
+    /// bypassing the `split_at_punctuation` preset in
+    /// `WordsExtractSettings`.
+    ///
+    /// This lets callers split words on arbitrary classes — e.g. script
+    /// boundaries or custom symbol sets — beyond the `All`/`Custom`
+    /// presets.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Returns `true` for characters that should end the
+    ///   current word and form a one-character word of their own.
+    pub fn with_split_predicate(mut self, predicate: impl Fn(char) -> bool + 'static) -> Self {
+        self.split_predicate = Rc::new(predicate);
+        self
+    }
     /// Merges a sequence of characters into a single word.
     ///
     /// # Arguments
@@ -127,18 +203,66 @@ impl WordExtractor {
                     .unwrap_or_else(|| unicode_char.clone())
             })
             .collect();
+        let text = if self.normalize_unicode {
+            // NFKC composes trailing combining diacritical marks into
+            // their base grapheme wherever a precomposed form exists.
+            text.nfkc().collect()
+        } else {
+            text
+        };
+
+        let extra_attrs = self
+            .extra_attrs
+            .iter()
+            .filter_map(|name| char_attr_value(first_char, name).map(|value| (name.clone(), value)))
+            .collect();
 
         Word {
             text,
             bbox: (x1, y1, x2, y2),
             rotation_degrees: rotation,
+            extra_attrs,
+        }
+    }
+
+    /// Returns `true` if `prev_char` and `curr_char` agree on every
+    /// attribute named in `self.extra_attrs`, so they may still merge into
+    /// the same word. Unrecognized attribute names never block a merge.
+    fn char_attrs_match(&self, prev_char: &Char, curr_char: &Char) -> bool {
+        self.extra_attrs
+            .iter()
+            .all(|name| char_attr_value(prev_char, name) == char_attr_value(curr_char, name))
+    }
+
+    /// Effective x tolerance for a character of the given size.
+    ///
+    /// When `x_tolerance_ratio` is set, the tolerance scales with
+    /// `char_size` instead of using the fixed `x_tolerance`.
+    #[inline]
+    fn effective_x_tolerance(&self, char_size: OrderedFloat<f32>) -> OrderedFloat<f32> {
+        match self.x_tolerance_ratio {
+            Some(ratio) => ratio * char_size,
+            None => self.x_tolerance,
+        }
+    }
+
+    /// Effective y tolerance for a character of the given size. See
+    /// [`Self::effective_x_tolerance`].
+    #[inline]
+    fn effective_y_tolerance(&self, char_size: OrderedFloat<f32>) -> OrderedFloat<f32> {
+        match self.y_tolerance_ratio {
+            Some(ratio) => ratio * char_size,
+            None => self.y_tolerance,
         }
     }
 
     /// Determines if a character should start a new word.
     ///
     /// Based on the position and rotation of the current character
-    /// relative to the previous character.
+    /// relative to the previous character. When `x_tolerance_ratio` or
+    /// `y_tolerance_ratio` is set, the threshold is recomputed from the
+    /// font size of `prev_char` — the trailing character of the word
+    /// built so far — instead of using a fixed absolute tolerance.
     ///
     /// # Arguments
     ///
@@ -150,14 +274,15 @@ impl WordExtractor {
     /// `true` if the current character should start a new word.
     pub fn char_begins_new_word(&self, prev_char: &Char, curr_char: &Char) -> bool {
         let (x, y, ax, bx, cx, ay, cy);
+        let char_size = prev_char.font_size;
 
         if (curr_char.rotation_degrees >= OrderedFloat(-0.001f32)
             && curr_char.rotation_degrees < OrderedFloat(45.0f32))
             || (curr_char.rotation_degrees >= OrderedFloat(315.0f32)
                 && curr_char.rotation_degrees < OrderedFloat(360.001f32))
         {
-            x = self.x_tolerance;
-            y = self.y_tolerance;
+            x = self.effective_x_tolerance(char_size);
+            y = self.effective_y_tolerance(char_size);
             ay = prev_char.bbox.1;
             cy = curr_char.bbox.1;
 
@@ -173,8 +298,8 @@ impl WordExtractor {
         } else if curr_char.rotation_degrees >= OrderedFloat(45.0f32)
             && curr_char.rotation_degrees < OrderedFloat(135.0f32)
         {
-            x = self.y_tolerance;
-            y = self.x_tolerance;
+            x = self.effective_y_tolerance(char_size);
+            y = self.effective_x_tolerance(char_size);
             ay = prev_char.bbox.0;
             cy = curr_char.bbox.0;
 
@@ -190,8 +315,8 @@ impl WordExtractor {
         } else if curr_char.rotation_degrees >= OrderedFloat(135.0f32)
             && curr_char.rotation_degrees < OrderedFloat(225.0f32)
         {
-            x = self.x_tolerance;
-            y = self.y_tolerance;
+            x = self.effective_x_tolerance(char_size);
+            y = self.effective_y_tolerance(char_size);
             ay = prev_char.bbox.3;
             cy = curr_char.bbox.3;
 
@@ -205,8 +330,8 @@ impl WordExtractor {
                 cx = curr_char.bbox.0;
             }
         } else {
-            x = self.y_tolerance;
-            y = self.x_tolerance;
+            x = self.effective_y_tolerance(char_size);
+            y = self.effective_x_tolerance(char_size);
             ay = prev_char.bbox.0;
             cy = curr_char.bbox.0;
 
@@ -224,51 +349,27 @@ impl WordExtractor {
         (cx < ax) || (cx > bx + x) || (cy > ay + y)
     }
 
-    /// Groups ordered characters into word groups.
-    ///
-    /// # Arguments
-    ///
-    /// * `ordered_chars` - Characters in reading order.
-    ///
-    /// # Returns
-    ///
-    /// A vector where each element is a group of characters forming a word.
-    pub fn iter_chars_to_words(&self, ordered_chars: Vec<Char>) -> Vec<Vec<Char>> {
-        let mut words = Vec::new();
-        let mut current_word: Vec<Char> = Vec::new();
-
-        for char in ordered_chars {
-            let text = &char.unicode_char.as_ref().unwrap();
-
-            if !self.keep_blank_chars && text.chars().all(|c| c.is_whitespace()) {
-                if !current_word.is_empty() {
-                    words.push(std::mem::take(&mut current_word));
-                }
-            } else if text.len() == 1
-                && self
-                    .split_at_punctuation
-                    .contains(&text.chars().next().unwrap())
-            {
-                if !current_word.is_empty() {
-                    words.push(std::mem::take(&mut current_word));
-                }
-                words.push(vec![char.clone()]);
-            } else if !current_word.is_empty()
-                && self.char_begins_new_word(current_word.last().unwrap(), &char)
-            {
-                words.push(std::mem::take(&mut current_word));
-                current_word.push(char.clone());
-            } else {
-                current_word.push(char.clone());
-            }
-        }
-
-        if !current_word.is_empty() {
-            words.push(current_word);
-        }
+    /// Returns `true` if `char`'s text should be dropped and end the current
+    /// word, per `keep_blank_chars`.
+    #[inline]
+    fn is_blank(&self, char: &Char) -> bool {
+        !self.keep_blank_chars
+            && char
+                .unicode_char
+                .as_ref()
+                .unwrap()
+                .chars()
+                .all(|c| c.is_whitespace())
+    }
 
-        words
+    /// Returns `true` if `char`'s text is a single character that, per
+    /// `split_predicate`, forms its own one-character word.
+    #[inline]
+    fn is_split_punctuation(&self, char: &Char) -> bool {
+        let text = char.unicode_char.as_ref().unwrap();
+        text.len() == 1 && (self.split_predicate)(text.chars().next().unwrap())
     }
+
     /// Sorts characters into reading order.
     ///
     /// Characters are first clustered by rotation, then sorted within
@@ -282,10 +383,33 @@ impl WordExtractor {
     ///
     /// Characters sorted in reading order.
     pub fn iter_sort_chars(&self, chars: &[Char]) -> Vec<Char> {
-        let mut result = Vec::with_capacity(chars.len());
+        self.iter_sort_chars_into_lines(chars)
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Sorts characters into reading order, keeping each line (the
+    /// rotation/position sub-cluster [`WordExtractor::iter_sort_chars`]
+    /// sorts within) as its own group.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters to sort.
+    ///
+    /// # Returns
+    ///
+    /// Lines of characters, each already sorted in reading order.
+    fn iter_sort_chars_into_lines(&self, chars: &[Char]) -> Vec<Vec<Char>> {
+        let mut lines = Vec::new();
         let rotation_degrees_key = |char: &Char| char.rotation_degrees;
 
-        let rotation_clusters = cluster_objects(chars, rotation_degrees_key, OrderedFloat(0.001));
+        let rotation_clusters = cluster_objects(
+            chars,
+            rotation_degrees_key,
+            OrderedFloat(0.001),
+            ClusterMode::Linkage,
+        );
 
         for rotation_cluster in rotation_clusters {
             if rotation_cluster.is_empty() {
@@ -297,7 +421,8 @@ impl WordExtractor {
                 true => |char: &Char| char.bbox.1,
                 false => |char: &Char| char.bbox.0,
             };
-            let sub_clusters = cluster_objects(&rotation_cluster, sub_key, self.y_tolerance);
+            let sub_clusters =
+                cluster_objects(&rotation_cluster, sub_key, self.y_tolerance, ClusterMode::Linkage);
 
             for mut sc in sub_clusters {
                 if (rotation_degrees >= OrderedFloat(-0.001f32)
@@ -331,46 +456,81 @@ impl WordExtractor {
                 } else {
                     sc.sort_by(|a, b| a.bbox.1.partial_cmp(&b.bbox.1).unwrap());
                 }
-                result.extend(sc);
+                lines.push(sc);
             }
         }
 
-        result
+        lines
     }
 
-    /// Extracts words along with their source characters.
+    /// Streams words (with their source characters) out of characters
+    /// already in reading order.
+    ///
+    /// Rotation-group boundaries and word breaks are detected lazily, one
+    /// character at a time, so no intermediate `Vec<Vec<Char>>` of rotation
+    /// or word groups is ever materialized: each word is emitted as soon as
+    /// [`WordExtractor::char_begins_new_word`] (or a rotation change, blank,
+    /// or split-punctuation character) ends it.
     ///
     /// # Arguments
     ///
-    /// * `chars` - The characters to process.
+    /// * `ordered_chars` - Characters already sorted into reading order.
     ///
     /// # Returns
     ///
-    /// A vector of tuples containing each word and its source characters.
-    pub fn iter_extract_tuples(&self, chars: &[Char]) -> Vec<(Word, Vec<Char>)> {
-        let ordered_chars = if self.use_text_flow {
+    /// An iterator yielding each word alongside the subslice of
+    /// `ordered_chars` it was built from.
+    pub fn words_iter_tuples<'a, 'c>(
+        &'a self,
+        ordered_chars: &'c [Char],
+    ) -> impl Iterator<Item = (Word, &'c [Char])> + 'a
+    where
+        'c: 'a,
+    {
+        WordsIter {
+            extractor: self,
+            chars: ordered_chars,
+            pos: 0,
+        }
+    }
+
+    /// Streams words out of characters already in reading order.
+    ///
+    /// A thin adapter over [`WordExtractor::words_iter_tuples`] that drops
+    /// the source-character subslice.
+    pub fn words_iter<'a, 'c>(
+        &'a self,
+        ordered_chars: &'c [Char],
+    ) -> impl Iterator<Item = Word> + 'a
+    where
+        'c: 'a,
+    {
+        self.words_iter_tuples(ordered_chars).map(|(word, _)| word)
+    }
+
+    /// Puts `chars` into reading order (unless `use_text_flow` is set).
+    fn order_chars(&self, chars: &[Char]) -> Vec<Char> {
+        if self.use_text_flow {
             chars.to_vec()
         } else {
             self.iter_sort_chars(chars)
-        };
-
-        let char_groups: Vec<Vec<Char>> = ordered_chars
-            .into_iter()
-            .chunk_by(|c| c.rotation_degrees)
-            .into_iter()
-            .map(|(_, group)| group.collect())
-            .collect();
-
-        let mut result = Vec::new();
-        for char_group in char_groups {
-            let word_groups = self.iter_chars_to_words(char_group);
-            for word_chars in word_groups {
-                let word = self.merge_chars(&word_chars);
-                result.push((word, word_chars));
-            }
         }
+    }
 
-        result
+    /// Extracts words along with their source characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters to process.
+    ///
+    /// # Returns
+    ///
+    /// A vector of tuples containing each word and its source characters.
+    pub fn iter_extract_tuples(&self, chars: &[Char]) -> Vec<(Word, Vec<Char>)> {
+        let ordered_chars = self.order_chars(chars);
+        self.words_iter_tuples(&ordered_chars)
+            .map(|(word, word_chars)| (word, word_chars.to_vec()))
+            .collect()
     }
 
     /// Extracts words from a sequence of characters.
@@ -385,16 +545,109 @@ impl WordExtractor {
     ///
     /// A vector of extracted words.
     pub fn extract_words(&self, chars: &[Char]) -> Vec<Word> {
-        self.iter_extract_tuples(chars)
-            .into_iter()
-            .map(|(word, _)| word)
-            .collect()
+        let ordered_chars = self.order_chars(chars);
+        self.words_iter(&ordered_chars).collect()
+    }
+
+    /// Extracts words from `chars`, invoking `f` for each one as it is
+    /// produced instead of materializing a `Vec<Word>` first.
+    ///
+    /// `f` receives, in order, the word's index across the whole input, the
+    /// index of the line it belongs to, its position within that line, its
+    /// rotation in degrees, and its text. Lines are the rotation/position
+    /// sub-clusters [`WordExtractor::iter_sort_chars`] sorts within; when
+    /// `use_text_flow` is set no clustering happens, so every word is
+    /// reported on a single line (index 0).
+    ///
+    /// This lets callers build inverted indexes or per-line word-count
+    /// statistics without re-deriving line membership from bboxes
+    /// afterward.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters to process.
+    /// * `f` - Called as `f(word_index, line_index, position_in_line, rotation_degrees, text)`.
+    pub fn for_each_word(
+        &self,
+        chars: &[Char],
+        mut f: impl FnMut(usize, usize, usize, f32, &str),
+    ) {
+        let lines = if self.use_text_flow {
+            vec![chars.to_vec()]
+        } else {
+            self.iter_sort_chars_into_lines(chars)
+        };
+
+        let mut word_index = 0usize;
+        for (line_index, line_chars) in lines.iter().enumerate() {
+            for (position_in_line, word) in self.words_iter(line_chars).enumerate() {
+                f(
+                    word_index,
+                    line_index,
+                    position_in_line,
+                    word.rotation_degrees.into_inner(),
+                    &word.text,
+                );
+                word_index += 1;
+            }
+        }
+    }
+}
+
+/// A streaming iterator over `(Word, &[Char])` built lazily from characters
+/// already in reading order, without ever collecting a `Vec<Vec<Char>>` of
+/// rotation or word groups.
+///
+/// Each word's characters form a contiguous subslice of the input: blank
+/// characters (when not kept) and split-punctuation characters always break
+/// a word, so no word ever straddles a gap that was skipped over.
+struct WordsIter<'a, 'c> {
+    extractor: &'a WordExtractor,
+    chars: &'c [Char],
+    pos: usize,
+}
+
+impl<'a, 'c> Iterator for WordsIter<'a, 'c> {
+    type Item = (Word, &'c [Char]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos < self.chars.len() && self.extractor.is_blank(&self.chars[self.pos]) {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        if start >= self.chars.len() {
+            return None;
+        }
+
+        if self.extractor.is_split_punctuation(&self.chars[start]) {
+            self.pos += 1;
+        } else {
+            self.pos += 1;
+            while self.pos < self.chars.len() {
+                let curr = &self.chars[self.pos];
+                if self.extractor.is_blank(curr) || self.extractor.is_split_punctuation(curr) {
+                    break;
+                }
+                let prev = &self.chars[self.pos - 1];
+                if curr.rotation_degrees != prev.rotation_degrees
+                    || self.extractor.char_begins_new_word(prev, curr)
+                    || !self.extractor.char_attrs_match(prev, curr)
+                {
+                    break;
+                }
+                self.pos += 1;
+            }
+        }
+
+        let group = &self.chars[start..self.pos];
+        Some((self.extractor.merge_chars(group), group))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pdfium_render::prelude::PdfColor;
     use crate::pages::Page;
     use crate::test_utils::load_pdfium;
 
@@ -456,4 +709,168 @@ mod tests {
             .collect();
         assert_eq!(horizontal_rtl[1].text, "baaabaaA/AAA");
     }
+
+    #[test]
+    fn test_for_each_word_matches_extract_words_and_tracks_lines() {
+        let project_root = env!("CARGO_MANIFEST_DIR");
+        let pdfium = load_pdfium();
+
+        let pdf_path = format!("{}/tests/data/words-extract.pdf", project_root);
+        let doc = pdfium.load_pdf_from_file(&pdf_path, None).unwrap();
+        let page = doc.pages().get(0).unwrap();
+        let pdf_page = Page::new(unsafe { std::mem::transmute(page) }, 0);
+
+        let objects = pdf_page.objects.borrow();
+        let chars = &objects.as_ref().unwrap().chars;
+
+        let settings = WordsExtractSettings {
+            ..Default::default()
+        };
+        let extractor = WordExtractor::new(&settings);
+        let words = extractor.extract_words(chars);
+
+        let mut streamed = Vec::new();
+        extractor.for_each_word(chars, |word_index, line_index, position_in_line, rotation, text| {
+            streamed.push((
+                word_index,
+                line_index,
+                position_in_line,
+                rotation,
+                text.to_string(),
+            ));
+        });
+
+        // Every word reported, in order, with its text and rotation intact.
+        assert_eq!(streamed.len(), words.len());
+        for (word, (word_index, _, _, rotation, text)) in words.iter().zip(streamed.iter()) {
+            assert_eq!(*text, word.text);
+            assert_eq!(*rotation, word.rotation_degrees.into_inner());
+            assert!(*word_index < words.len());
+        }
+
+        // word_index is strictly increasing, and position_in_line restarts
+        // at 0 whenever line_index changes.
+        let mut last_word_index = None;
+        let mut seen_positions: HashMap<usize, usize> = HashMap::new();
+        for (word_index, line_index, position_in_line, _, _) in &streamed {
+            if let Some(last) = last_word_index {
+                assert_eq!(*word_index, last + 1);
+            }
+            last_word_index = Some(*word_index);
+
+            let expected_position = seen_positions.entry(*line_index).or_insert(0);
+            assert_eq!(position_in_line, expected_position);
+            *expected_position += 1;
+        }
+    }
+
+    fn char_at(unicode_char: &str, x1: f32, x2: f32) -> Char {
+        Char {
+            unicode_char: Some(unicode_char.to_string()),
+            bbox: (
+                OrderedFloat(x1),
+                OrderedFloat(0.0),
+                OrderedFloat(x2),
+                OrderedFloat(10.0),
+            ),
+            rotation_degrees: OrderedFloat(0.0),
+            upright: true,
+            font_size: OrderedFloat(10.0),
+            font_name: None,
+            fill_color: PdfColor::new(0, 0, 0, 255),
+            text_matrix: (1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+        }
+    }
+
+    #[test]
+    fn test_merge_chars_normalizes_unicode() {
+        // "e" followed by a combining acute accent (U+0301) NFKC-composes
+        // into the single precomposed grapheme "é".
+        let chars = vec![char_at("e", 0.0, 1.0), char_at("\u{0301}", 1.0, 2.0)];
+
+        let settings = WordsExtractSettings {
+            normalize_unicode: true,
+            ..Default::default()
+        };
+        let extractor = WordExtractor::new(&settings);
+        assert_eq!(extractor.merge_chars(&chars).text, "\u{e9}");
+
+        let settings_raw = WordsExtractSettings {
+            normalize_unicode: false,
+            ..Default::default()
+        };
+        let extractor_raw = WordExtractor::new(&settings_raw);
+        assert_eq!(extractor_raw.merge_chars(&chars).text, "e\u{0301}");
+    }
+
+    #[test]
+    fn test_with_split_predicate_overrides_preset() {
+        let chars = vec![
+            char_at("a", 0.0, 1.0),
+            char_at("#", 1.0, 2.0),
+            char_at("b", 2.0, 3.0),
+        ];
+
+        let settings = WordsExtractSettings::default();
+        let extractor =
+            WordExtractor::new(&settings).with_split_predicate(|c| c == '#');
+        let words = extractor.extract_words(&chars);
+        let texts: Vec<&str> = words.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, vec!["a", "#", "b"]);
+    }
+
+    #[test]
+    fn test_x_tolerance_ratio_scales_with_char_size() {
+        let small_gap = char_at("b", 11.0, 12.0);
+        let mut prev = char_at("a", 0.0, 1.0);
+        prev.font_size = OrderedFloat(2.0);
+
+        // Gap of 10.0 comfortably clears a ratio-based tolerance derived
+        // from the small prev char's font size (2.0 * 1.0 = 2.0) ...
+        let settings = WordsExtractSettings {
+            x_tolerance_ratio: Some(NonNegativeF32::new_unchecked(1.0)),
+            ..Default::default()
+        };
+        let extractor = WordExtractor::new(&settings);
+        assert!(extractor.char_begins_new_word(&prev, &small_gap));
+
+        // ... but the same gap stays within a large fixed x_tolerance.
+        let settings_absolute = WordsExtractSettings {
+            x_tolerance: NonNegativeF32::new_unchecked(20.0),
+            ..Default::default()
+        };
+        let extractor_absolute = WordExtractor::new(&settings_absolute);
+        assert!(!extractor_absolute.char_begins_new_word(&prev, &small_gap));
+    }
+
+    #[test]
+    fn test_extra_attrs_splits_words_on_font_change() {
+        let mut bold_a = char_at("A", 0.0, 1.0);
+        bold_a.font_name = Some("Bold".to_string());
+        let mut bold_b = char_at("B", 1.0, 2.0);
+        bold_b.font_name = Some("Bold".to_string());
+        let mut regular_c = char_at("C", 2.0, 3.0);
+        regular_c.font_name = Some("Regular".to_string());
+        let chars = vec![bold_a, bold_b, regular_c];
+
+        let settings = WordsExtractSettings {
+            extra_attrs: vec!["fontname".to_string()],
+            ..Default::default()
+        };
+        let extractor = WordExtractor::new(&settings);
+        let words = extractor.extract_words(&chars);
+        let texts: Vec<&str> = words.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, vec!["AB", "C"]);
+        assert_eq!(
+            words[0].extra_attrs,
+            vec![("fontname".to_string(), "Bold".to_string())]
+        );
+
+        // Without extra_attrs, the font change alone doesn't split the word.
+        let settings_default = WordsExtractSettings::default();
+        let extractor_default = WordExtractor::new(&settings_default);
+        let words_default = extractor_default.extract_words(&chars);
+        let texts_default: Vec<&str> = words_default.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts_default, vec!["ABC"]);
+    }
 }